@@ -9,6 +9,20 @@
     [ ! "$status" -eq 0 ]
 }
 
+@test "should reject quarantine when wiping with budget" {
+    run gradle-wiper disk budget --max 20GiB --quarantine
+
+    [[ "$output" == *"cannot be used with"* ]]
+    [ ! "$status" -eq 0 ]
+}
+
+@test "should reject flags not supported by the chosen mode" {
+    run gradle-wiper disk shallow --per-project
+
+    [[ "$output" == *"cannot be used with the 'shallow' mode"* ]]
+    [ ! "$status" -eq 0 ]
+}
+
 @test "should detect usages of disk" {
     run $HOME/IdeaProjects/aaw/gradlew tasks -q -p $HOME/IdeaProjects/aaw
     run gradle-wiper disk evaluate
@@ -36,9 +50,16 @@
     [ -d $HOME/IdeaProjects/aaw/.gradle ]
 }
 
+@test "should refuse disk deep wiping without confirmation" {
+    run gradle-wiper disk deep
+
+    [[ "$output" == *"Refusing to deep wipe disk space without confirmation"* ]]
+    [ ! "$status" -eq 0 ]
+}
+
 @test "should perform disk deep wiping" {
     run $HOME/IdeaProjects/gradlew shadowJar -q -p $HOME/IdeaProjects/aaw
-    run gradle-wiper disk deep
+    run gradle-wiper disk deep --yes
 
     [[ "$output" == *"Reclaimed disk space"* ]]
     [ "$status" -eq 0 ]
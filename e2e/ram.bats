@@ -9,6 +9,13 @@
     [ ! "$status" -eq 0 ]
 }
 
+@test "should reject flags not supported by the chosen mode" {
+    run gradle-wiper ram evaluate --idle-for 10m
+
+    [[ "$output" == *"cannot be used with the 'evaluate' mode"* ]]
+    [ ! "$status" -eq 0 ]
+}
+
 @test "should detect usages of ram" {
     run $HOME/IdeaProjects/aaw/gradlew tasks -q -p $HOME/IdeaProjects//aaw
     run gradle-wiper ram evaluate
@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: MIT
 
 mod arguments;
+mod configuration;
 mod feedback;
+mod interactive;
 mod logging;
+mod reports;
 
 pub use arguments::parsed_arguments;
 pub use feedback::show_execution_outcome;
+pub use interactive::{confirm_wipe, select_wipe_targets};
 pub use logging::setup_logging;
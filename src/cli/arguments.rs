@@ -1,28 +1,455 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::{MachineResource, WipeAction};
-use clap::{Args, Parser, Subcommand, ValueEnum};
+use crate::cli::configuration::{
+    Configuration, ProjectsConfiguration, ProtectedProcessesConfiguration, load_configuration,
+};
+use crate::core::models::{
+    CategoryFilter, DiskCached, ExecutionOptions, MachineResource, MemoryCached, ProcessFilters, ProcessProtection,
+    ProjectDiscovery, WipeAction,
+};
+use anyhow::Context;
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use glob::Pattern;
+use std::path::PathBuf;
+use std::time::Duration;
+use ubyte::ByteUnit;
 
-#[derive(ValueEnum, Debug, Clone)]
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
 enum ExecutionMode {
     Evaluate,
     Shallow,
     Deep,
 }
 
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+enum DiskExecutionMode {
+    Evaluate,
+    Shallow,
+    Deep,
+    Budget,
+    Interactive,
+    PruneDistributions,
+    PruneToolchains,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Yaml,
+}
+
+#[derive(Args)]
+struct CommonArguments {
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// Gradle user home to inspect (defaults to GRADLE_USER_HOME or ~/.gradle)
+    #[arg(short, long, value_name = "PATH")]
+    pub gradle_user_home: Option<PathBuf>,
+
+    /// Lists what would be reclaimed without removing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Skips confirmation prompts, required to deep wipe disk space non-interactively
+    #[arg(short, long)]
+    pub yes: bool,
+
+    /// How the outcome of this execution is reported
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
+
 #[derive(Args)]
-struct WrappedArguments {
+struct DiskArguments {
+    #[arg(value_enum)]
+    pub mode: DiskExecutionMode,
+
+    #[command(flatten)]
+    pub common: CommonArguments,
+
+    /// Folder to scan for Gradle projects (repeatable, replaces configured roots)
+    #[arg(long = "project-root", value_name = "PATH")]
+    pub project_roots: Vec<PathBuf>,
+
+    /// Glob that Gradle project paths must match (repeatable)
+    #[arg(long = "include-projects", value_name = "GLOB", value_parser = parse_glob)]
+    pub include_projects: Vec<Pattern>,
+
+    /// Glob for paths skipped when scanning for Gradle projects (repeatable)
+    #[arg(long = "exclude-projects", value_name = "GLOB", value_parser = parse_glob)]
+    pub exclude_projects: Vec<Pattern>,
+
+    /// How deep project roots are scanned for Gradle projects
+    #[arg(long, value_name = "DEPTH")]
+    pub max_depth: Option<usize>,
+
+    /// Breaks down disk usage per Gradle project when evaluating
+    #[arg(long)]
+    pub per_project: bool,
+
+    /// Only wipes shared cache entries not used for this long (e.g. 30d, 2weeks)
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub older_than: Option<Duration>,
+
+    /// Disk space that Gradle caches may take when wiping with budget (e.g. 20GiB)
+    #[arg(long, value_name = "SIZE", value_parser = parse_byte_unit, required_if_eq("mode", "budget"))]
+    pub max: Option<ByteUnit>,
+
+    /// How long to wait for running Gradle builds to finish before giving up (e.g. 10m)
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub wait: Option<Duration>,
+
+    /// Wipes disk space even when Gradle builds seem to be running
+    #[arg(long)]
+    pub force: bool,
+
+    /// Only wipes these categories, among the ones wiped in the chosen mode (e.g. build-cache,project-build)
+    #[arg(long, value_name = "CATEGORIES", value_delimiter = ',', value_parser = parse_disk_category)]
+    pub only: Vec<DiskCached>,
+
+    /// Never wipes these categories (e.g. maven-local)
+    #[arg(long, value_name = "CATEGORIES", value_delimiter = ',', value_parser = parse_disk_category)]
+    pub except: Vec<DiskCached>,
+
+    /// Moves wiped files into a quarantine, from where they can be restored until purged
+    #[arg(long, conflicts_with = "max")]
+    pub quarantine: bool,
+
+    /// Stops Gradle daemons, their workers and Kotlin daemons before wiping disk space
+    #[arg(long)]
+    pub stop_daemons: bool,
+
+    /// How long daemons may take to shut down before being killed, when stopping them
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration, default_value = "10s")]
+    pub grace_period: Duration,
+}
+
+#[derive(Args)]
+struct RamArguments {
     #[arg(value_enum)]
     pub mode: ExecutionMode,
 
-    #[arg(short, long)]
-    pub verbose: bool,
+    #[command(flatten)]
+    pub common: CommonArguments,
+
+    /// Lists every JVM process found when evaluating, instead of only totals per category
+    #[arg(long)]
+    pub processes: bool,
+
+    /// Only stops Gradle daemons idle for this long since their last build, along with --only gradle-daemon (e.g. 10m)
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub idle_for: Option<Duration>,
+
+    /// Only stops processes started longer than this ago (e.g. 2h)
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub older_than: Option<Duration>,
+
+    /// Only stops Gradle daemons running this Gradle version (e.g. 8.5)
+    #[arg(long, value_name = "VERSION")]
+    pub gradle_version: Option<String>,
+
+    /// Also stops Gradle daemons that are busy running a build
+    #[arg(long)]
+    pub force: bool,
+
+    /// Only stops these categories, among the ones stopped in the chosen mode (e.g. gradle-daemon,kotlin-daemon)
+    #[arg(long, value_name = "CATEGORIES", value_delimiter = ',', value_parser = parse_memory_category)]
+    pub only: Vec<MemoryCached>,
+
+    /// Never stops these categories (e.g. bazel-server)
+    #[arg(long, value_name = "CATEGORIES", value_delimiter = ',', value_parser = parse_memory_category)]
+    pub except: Vec<MemoryCached>,
+
+    /// Glob for launcher classes of processes that are never stopped (repeatable)
+    #[arg(long = "protect", value_name = "GLOB", value_parser = parse_glob)]
+    pub protected_launchers: Vec<Pattern>,
+
+    /// How long JVM processes may take to shut down before being killed
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration, default_value = "10s")]
+    pub grace_period: Duration,
+}
+
+#[derive(Args)]
+struct AllArguments {
+    #[arg(value_enum)]
+    pub mode: ExecutionMode,
+
+    #[command(flatten)]
+    pub common: CommonArguments,
+
+    /// How long to wait for running Gradle builds to finish before giving up (e.g. 10m)
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub wait: Option<Duration>,
+
+    /// Also stops busy Gradle daemons, and wipes disk space even when Gradle builds seem to be running
+    #[arg(long)]
+    pub force: bool,
+
+    /// How long JVM processes may take to shut down before being killed
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration, default_value = "10s")]
+    pub grace_period: Duration,
+}
+
+#[derive(Args)]
+struct RestoreArguments {
+    /// Quarantine to restore, as reported when wiping with --quarantine
+    pub id: String,
+
+    #[command(flatten)]
+    pub common: CommonArguments,
+}
+
+#[derive(Args)]
+struct PurgeArguments {
+    /// Only purges quarantines created longer than this ago (e.g. 7d)
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub older_than: Option<Duration>,
+
+    #[command(flatten)]
+    pub common: CommonArguments,
+}
+
+fn parse_byte_unit(raw_size: &str) -> Result<ByteUnit, String> {
+    raw_size.parse::<ByteUnit>().map_err(|error| error.to_string())
+}
+
+fn parse_glob(raw_pattern: &str) -> Result<Pattern, String> {
+    Pattern::new(raw_pattern).map_err(|error| error.to_string())
+}
+
+fn parse_disk_category(raw_category: &str) -> Result<DiskCached, String> {
+    parse_category(raw_category, &DiskCached::all(), DiskCached::identifier)
+}
+
+fn parse_memory_category(raw_category: &str) -> Result<MemoryCached, String> {
+    parse_category(raw_category, &MemoryCached::ALL, MemoryCached::identifier)
+}
+
+fn parse_category<T: Copy>(
+    raw_category: &str,
+    categories: &[T],
+    identifier: fn(&T) -> &'static str,
+) -> Result<T, String> {
+    categories
+        .iter()
+        .find(|category| identifier(category) == raw_category)
+        .copied()
+        .ok_or_else(|| {
+            let expected = categories.iter().map(identifier).collect::<Vec<_>>().join(", ");
+            format!("expected one of {expected}")
+        })
 }
 
-impl From<&WrappedArguments> for WipeAction {
-    fn from(value: &WrappedArguments) -> Self {
-        match value.mode {
+impl DiskArguments {
+    fn misused_flags(&self) -> Vec<&'static str> {
+        let wiping = matches!(
+            self.mode,
+            DiskExecutionMode::Shallow | DiskExecutionMode::Deep | DiskExecutionMode::Interactive
+        );
+
+        let flags = [
+            (
+                "--per-project",
+                self.per_project,
+                self.mode == DiskExecutionMode::Evaluate,
+            ),
+            ("--older-than", self.older_than.is_some(), wiping),
+            ("--max", self.max.is_some(), self.mode == DiskExecutionMode::Budget),
+        ];
+
+        unsupported_flags(&flags)
+    }
+
+    fn wipe_action(&self) -> WipeAction {
+        match self.mode {
+            DiskExecutionMode::Evaluate => WipeAction::Evaluate,
+            DiskExecutionMode::Shallow => WipeAction::ShallowWipe,
+            DiskExecutionMode::Deep => WipeAction::DeepWipe,
+            DiskExecutionMode::Budget => {
+                WipeAction::BudgetWipe(self.max.expect("Budget mode always comes with a maximum size"))
+            },
+            DiskExecutionMode::Interactive => WipeAction::SelectiveWipe,
+            DiskExecutionMode::PruneDistributions => WipeAction::PruneDistributions,
+            DiskExecutionMode::PruneToolchains => WipeAction::PruneToolchains,
+        }
+    }
+
+    fn execution_options(&self, configuration: Configuration) -> anyhow::Result<ExecutionOptions> {
+        let options = ExecutionOptions {
+            project_discovery: self.project_discovery(configuration.projects)?,
+            per_project: self.per_project,
+            older_than: self.older_than,
+            wait_for_builds: self.wait,
+            force: self.force,
+            stop_daemons: self.stop_daemons,
+            quarantine: self.quarantine,
+            disk_categories: CategoryFilter {
+                only: self.only.clone(),
+                except: self.except.clone(),
+            },
+            grace_period: self.grace_period,
+            protection: process_protection(configuration.protected_processes, &[])?,
+            ..ExecutionOptions::from(&self.common)
+        };
+
+        Ok(options)
+    }
+
+    fn project_discovery(&self, configured: ProjectsConfiguration) -> anyhow::Result<ProjectDiscovery> {
+        let roots = if self.project_roots.is_empty() {
+            configured.roots
+        } else {
+            self.project_roots.clone()
+        };
+
+        let mut include = compile_globs(&configured.include)?;
+        include.extend(self.include_projects.iter().cloned());
+
+        let mut exclude = compile_globs(&configured.exclude)?;
+        exclude.extend(self.exclude_projects.iter().cloned());
+
+        let discovery = ProjectDiscovery {
+            roots,
+            include,
+            exclude,
+            max_depth: self.max_depth.or(configured.max_depth),
+        };
+
+        Ok(discovery)
+    }
+}
+
+impl AllArguments {
+    fn execution_options(&self, configuration: Configuration) -> anyhow::Result<ExecutionOptions> {
+        let projects = configuration.projects;
+
+        let options = ExecutionOptions {
+            project_discovery: ProjectDiscovery {
+                roots: projects.roots,
+                include: compile_globs(&projects.include)?,
+                exclude: compile_globs(&projects.exclude)?,
+                max_depth: projects.max_depth,
+            },
+            wait_for_builds: self.wait,
+            force: self.force,
+            grace_period: self.grace_period,
+            protection: process_protection(configuration.protected_processes, &[])?,
+            ..ExecutionOptions::from(&self.common)
+        };
+
+        Ok(options)
+    }
+}
+
+impl RamArguments {
+    fn misused_flags(&self) -> Vec<&'static str> {
+        let evaluating = self.mode == ExecutionMode::Evaluate;
+
+        let flags = [
+            ("--processes", self.processes, evaluating),
+            ("--idle-for", self.idle_for.is_some(), !evaluating),
+            ("--older-than", self.older_than.is_some(), !evaluating),
+            ("--gradle-version", self.gradle_version.is_some(), !evaluating),
+        ];
+
+        unsupported_flags(&flags)
+    }
+
+    fn execution_options(&self, configuration: Configuration) -> anyhow::Result<ExecutionOptions> {
+        let options = ExecutionOptions {
+            grace_period: self.grace_period,
+            per_process: self.processes,
+            memory_categories: CategoryFilter {
+                only: self.only.clone(),
+                except: self.except.clone(),
+            },
+            force: self.force,
+            process_filters: ProcessFilters {
+                idle_for: self.idle_for,
+                older_than: self.older_than,
+                gradle_version: self.gradle_version.clone(),
+            },
+            protection: process_protection(configuration.protected_processes, &self.protected_launchers)?,
+            ..ExecutionOptions::from(&self.common)
+        };
+
+        Ok(options)
+    }
+}
+
+fn process_protection(
+    configured: ProtectedProcessesConfiguration,
+    extra_launchers: &[Pattern],
+) -> anyhow::Result<ProcessProtection> {
+    let mut launchers = compile_globs(&configured.launcher_patterns())?;
+    launchers.extend(extra_launchers.iter().cloned());
+
+    let protection = ProcessProtection {
+        launchers,
+        pids: configured.pids,
+        names: configured.names,
+    };
+
+    Ok(protection)
+}
+
+// Flags given to a mode that would silently ignore them, as (flag, given, supported by the mode)
+fn unsupported_flags(flags: &[(&'static str, bool, bool)]) -> Vec<&'static str> {
+    flags
+        .iter()
+        .filter(|(_, given, supported)| *given && !supported)
+        .map(|(flag, _, _)| *flag)
+        .collect()
+}
+
+// Modes are values rather than subcommands, hence clap can't tell by itself which flags each one accepts
+fn reject_misused_flags(subcommand: &str, misused: &[&str], mode: &impl ValueEnum) {
+    let Some(flag) = misused.first() else {
+        return;
+    };
+
+    let mode = mode
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default();
+    let mut command = CliParser::command();
+    command.build();
+
+    command
+        .find_subcommand_mut(subcommand)
+        .expect("Flags are always given to a known subcommand")
+        .error(
+            ErrorKind::ArgumentConflict,
+            format!("the argument '{flag}' cannot be used with the '{mode}' mode"),
+        )
+        .exit()
+}
+
+impl From<&CommonArguments> for ExecutionOptions {
+    fn from(value: &CommonArguments) -> Self {
+        ExecutionOptions {
+            gradle_user_home: value.gradle_user_home.clone(),
+            dry_run: value.dry_run,
+            ..ExecutionOptions::default()
+        }
+    }
+}
+
+fn compile_globs(raw_patterns: &[String]) -> anyhow::Result<Vec<Pattern>> {
+    raw_patterns
+        .iter()
+        .map(|raw| Pattern::new(raw).with_context(|| format!("Invalid glob in configuration : {raw}")))
+        .collect()
+}
+
+impl From<&ExecutionMode> for WipeAction {
+    fn from(value: &ExecutionMode) -> Self {
+        match value {
             ExecutionMode::Evaluate => WipeAction::Evaluate,
             ExecutionMode::Shallow => WipeAction::ShallowWipe,
             ExecutionMode::Deep => WipeAction::DeepWipe,
@@ -40,14 +467,91 @@ struct CliParser {
 
 #[derive(Subcommand)]
 enum Commands {
-    Disk(WrappedArguments),
-    Ram(WrappedArguments),
+    Disk(DiskArguments),
+    Ram(RamArguments),
+    All(AllArguments),
+    /// Restores files moved into quarantine by a wipe
+    Restore(RestoreArguments),
+    /// Removes quarantined files for good
+    Purge(PurgeArguments),
 }
 
-pub fn parsed_arguments() -> (MachineResource, WipeAction, bool) {
-    let cli = CliParser::parse();
-    match cli.command {
-        Commands::Disk(args) => (MachineResource::DiskSpace, WipeAction::from(&args), args.verbose),
-        Commands::Ram(args) => (MachineResource::RamMemory, WipeAction::from(&args), args.verbose),
+pub struct ParsedArguments {
+    pub resource: MachineResource,
+    pub action: WipeAction,
+    pub options: ExecutionOptions,
+    pub output: OutputFormat,
+    pub verbose: bool,
+    pub assume_yes: bool,
+}
+
+impl ParsedArguments {
+    fn new(resource: MachineResource, action: WipeAction, common: &CommonArguments, options: ExecutionOptions) -> Self {
+        Self {
+            resource,
+            action,
+            options,
+            output: common.output,
+            verbose: common.verbose,
+            assume_yes: common.yes,
+        }
     }
 }
+
+pub fn parsed_arguments() -> anyhow::Result<ParsedArguments> {
+    let cli = CliParser::parse();
+    let configuration = load_configuration()?;
+
+    let parsed = match cli.command {
+        Commands::Disk(args) => {
+            reject_misused_flags("disk", &args.misused_flags(), &args.mode);
+            let options = args.execution_options(configuration)?;
+            ParsedArguments::new(MachineResource::DiskSpace, args.wipe_action(), &args.common, options)
+        },
+        Commands::Ram(args) => {
+            reject_misused_flags("ram", &args.misused_flags(), &args.mode);
+            let options = args.execution_options(configuration)?;
+            ParsedArguments::new(
+                MachineResource::RamMemory,
+                WipeAction::from(&args.mode),
+                &args.common,
+                options,
+            )
+        },
+        Commands::Restore(args) => {
+            let options = ExecutionOptions {
+                quarantine_id: Some(args.id.clone()),
+                ..ExecutionOptions::from(&args.common)
+            };
+            ParsedArguments::new(
+                MachineResource::DiskSpace,
+                WipeAction::RestoreQuarantine,
+                &args.common,
+                options,
+            )
+        },
+        Commands::Purge(args) => {
+            let options = ExecutionOptions {
+                older_than: args.older_than,
+                ..ExecutionOptions::from(&args.common)
+            };
+            ParsedArguments::new(
+                MachineResource::DiskSpace,
+                WipeAction::PurgeQuarantines,
+                &args.common,
+                options,
+            )
+        },
+        Commands::All(args) => {
+            let options = args.execution_options(configuration)?;
+            ParsedArguments::new(
+                MachineResource::AllResources,
+                WipeAction::from(&args.mode),
+                &args.common,
+                options,
+            )
+        },
+    };
+
+    Ok(parsed)
+}
@@ -0,0 +1,160 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use directories::BaseDirs;
+use log::debug;
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Configuration {
+    pub projects: ProjectsConfiguration,
+    pub protected_processes: ProtectedProcessesConfiguration,
+}
+
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectsConfiguration {
+    pub roots: Vec<PathBuf>,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub max_depth: Option<usize>,
+}
+
+// All IDEs built on the IntelliJ platform (IntelliJ IDEA, Android Studio, etc.) share the same launcher class
+const BUILTIN_PROTECTED_LAUNCHERS: [&str; 1] = ["com.intellij.*"];
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProtectedProcessesConfiguration {
+    pub launchers: Vec<String>,
+    pub pids: Vec<u32>,
+    pub names: Vec<String>,
+    pub builtin: bool,
+}
+
+impl Default for ProtectedProcessesConfiguration {
+    fn default() -> Self {
+        Self {
+            launchers: vec![],
+            pids: vec![],
+            names: vec![],
+            builtin: true,
+        }
+    }
+}
+
+impl ProtectedProcessesConfiguration {
+    pub fn launcher_patterns(&self) -> Vec<String> {
+        let builtin = if self.builtin {
+            BUILTIN_PROTECTED_LAUNCHERS.map(String::from).to_vec()
+        } else {
+            vec![]
+        };
+
+        builtin.into_iter().chain(self.launchers.iter().cloned()).collect()
+    }
+}
+
+pub fn load_configuration() -> anyhow::Result<Configuration> {
+    let Some(configuration_file) = locate_configuration_file().filter(|path| path.exists()) else {
+        return Ok(Configuration::default());
+    };
+
+    debug!("Loading configuration from : {}", configuration_file.to_string_lossy());
+
+    let contents = fs::read_to_string(&configuration_file)?;
+    parse_configuration(&contents)
+        .with_context(|| format!("Invalid configuration file : {}", configuration_file.to_string_lossy()))
+}
+
+fn parse_configuration(contents: &str) -> anyhow::Result<Configuration> {
+    Ok(toml::from_str(contents)?)
+}
+
+fn locate_configuration_file() -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| BaseDirs::new().map(|dirs| dirs.home_dir().join(".config")))?;
+
+    Some(config_home.join("gradle-wiper").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::configuration::{
+        Configuration, ProjectsConfiguration, ProtectedProcessesConfiguration, parse_configuration,
+    };
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_parse_empty_configuration() {
+        let configuration = parse_configuration("").expect("Cannot parse configuration");
+
+        assert_eq!(configuration, Configuration::default());
+    }
+
+    #[test]
+    fn should_parse_project_discovery_configuration() {
+        let contents = r#"
+            [projects]
+            roots = ["~/work", "/opt/src"]
+            exclude = ["**/node_modules"]
+            max_depth = 4
+        "#;
+
+        let configuration = parse_configuration(contents).expect("Cannot parse configuration");
+
+        let expected = Configuration {
+            projects: ProjectsConfiguration {
+                roots: vec![PathBuf::from("~/work"), PathBuf::from("/opt/src")],
+                include: vec![],
+                exclude: vec![String::from("**/node_modules")],
+                max_depth: Some(4),
+            },
+            protected_processes: ProtectedProcessesConfiguration::default(),
+        };
+
+        assert_eq!(configuration, expected);
+    }
+
+    #[test]
+    fn should_parse_protected_processes_configuration() {
+        let contents = r#"
+            [protected_processes]
+            launchers = ["kafka.Kafka"]
+            names = ["zookeeper"]
+            builtin = false
+        "#;
+
+        let configuration = parse_configuration(contents).expect("Cannot parse configuration");
+
+        let expected = ProtectedProcessesConfiguration {
+            launchers: vec![String::from("kafka.Kafka")],
+            pids: vec![],
+            names: vec![String::from("zookeeper")],
+            builtin: false,
+        };
+
+        assert_eq!(configuration.protected_processes, expected);
+        assert_eq!(expected.launcher_patterns(), vec![String::from("kafka.Kafka")]);
+        assert_eq!(
+            ProtectedProcessesConfiguration::default().launcher_patterns(),
+            vec![String::from("com.intellij.*")]
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_configuration_entries() {
+        let contents = r#"
+            [projects]
+            folders = ["~/work"]
+        "#;
+
+        assert!(parse_configuration(contents).is_err());
+    }
+}
@@ -1,19 +1,56 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::{EvaluationOutcome, ExecutionOutcome, MachineResource, WipingOutcome};
+use crate::cli::arguments::OutputFormat;
+use crate::cli::reports;
+use crate::core::models::{
+    CombinedOutcome, DryRunOutcome, EvaluationOutcome, ExecutionOutcome, MachineResource, PathRemoval, ProcessUsage,
+    ProjectUsage, ProtectedProcess, PruningOutcome, RemovalStatus, RestoringOutcome, StoppedProcess, UseCase,
+    WipingOutcome,
+};
 use comfy_table::modifiers::UTF8_ROUND_CORNERS;
 use comfy_table::presets::UTF8_FULL;
 use comfy_table::{ContentArrangement, Table};
-use log::info;
+use log::{info, warn};
+use ubyte::ByteUnit;
 
-pub fn show_execution_outcome(resource: &MachineResource, outcome: &ExecutionOutcome) -> anyhow::Result<()> {
+pub fn show_execution_outcome(
+    resource: &MachineResource,
+    outcome: &ExecutionOutcome,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Table => show_as_table(resource, outcome),
+        OutputFormat::Json => println!("{}", reports::to_json(resource, outcome)?),
+        OutputFormat::Yaml => print!("{}", reports::to_yaml(resource, outcome)?),
+    }
+
+    Ok(())
+}
+
+fn show_as_table(resource: &MachineResource, outcome: &ExecutionOutcome) {
     match outcome {
         ExecutionOutcome::Evaluation(evaluation) => used_resources(resource, evaluation),
         ExecutionOutcome::Wiping(wipping) => cleanup_outcome(resource, wipping),
+        ExecutionOutcome::DryRun(dry_run) => simulated_cleanup(resource, dry_run),
+        ExecutionOutcome::Pruning(pruning) => pruning_outcome(resource, pruning),
+        ExecutionOutcome::Restoring(restoring) => restoring_outcome(restoring),
+        ExecutionOutcome::Combined(combined) => combined_outcome(combined),
     }
+}
 
-    Ok(())
+fn combined_outcome(outcome: &CombinedOutcome) {
+    outcome
+        .sections
+        .iter()
+        .for_each(|(resource, outcome)| show_as_table(resource, outcome));
+
+    info!("Grand totals :");
+    outcome
+        .totals()
+        .into_iter()
+        .for_each(|(resource, amount)| info!("  - {} : {:.1}", resource, amount));
+    info!("");
 }
 
 fn used_resources(resource: &MachineResource, outcome: &EvaluationOutcome) {
@@ -27,30 +64,373 @@ fn used_resources(resource: &MachineResource, outcome: &EvaluationOutcome) {
         return;
     }
 
-    let rows = allocated
+    println!("{}", used_resources_table(outcome));
+
+    if !outcome.projects.is_empty() {
+        used_resources_per_project(&outcome.projects);
+    }
+
+    if !outcome.processes.is_empty() {
+        used_resources_per_process(&outcome.processes);
+    }
+
+    info!("");
+    info!("Total resources ({}) : {:.1}", resource, &outcome.total_size);
+    info!("");
+}
+
+fn used_resources_table(outcome: &EvaluationOutcome) -> Table {
+    let rows = outcome
+        .resources
         .iter()
-        .map(|res| vec![format!("{}", res.use_case), format!("{}", res.amount)])
+        .map(|res| {
+            let mut row = vec![format!("{}", res.use_case), format!("{}", res.amount)];
+
+            if !outcome.jvm_memory.is_empty() {
+                let usage = outcome.jvm_memory.iter().find(|usage| usage.use_case == res.use_case);
+                row.extend(match usage {
+                    Some(usage) => vec![
+                        format!("{}", usage.heap_used),
+                        format!("{}", usage.heap_committed),
+                        format!("{}", usage.metaspace_used),
+                        format!("{}", usage.metaspace_committed),
+                    ],
+                    None => vec![String::from("-"); 4],
+                });
+            }
+
+            row
+        })
         .collect::<Vec<_>>();
 
-    let mut table = Table::new();
+    let header = if outcome.jvm_memory.is_empty() {
+        vec!["What", "Total Size"]
+    } else {
+        vec![
+            "What",
+            "Total Size",
+            "Heap used",
+            "Heap committed",
+            "Metaspace used",
+            "Metaspace committed",
+        ]
+    };
+
+    let mut table = new_table(header);
+    table.add_rows(rows);
 
     table
-        .load_preset(UTF8_FULL)
-        .apply_modifier(UTF8_ROUND_CORNERS)
-        .set_content_arrangement(ContentArrangement::Dynamic)
-        .set_width(100)
-        .set_header(vec!["What", "Total Size"])
-        .add_rows(rows);
+}
+
+fn used_resources_per_project(projects: &[ProjectUsage]) {
+    info!("");
+    println!("{}", used_resources_per_project_table(projects));
+}
+
+fn used_resources_per_project_table(projects: &[ProjectUsage]) -> Table {
+    let rows = projects
+        .iter()
+        .map(|usage| {
+            vec![
+                format!("{}", usage.project.to_string_lossy()),
+                format!("{}", usage.build_output),
+                format!("{}", usage.gradle_metadata),
+                format!("{}", usage.idea_metadata),
+                format!("{}", usage.total()),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = new_table(vec!["Project", "build/", ".gradle/", ".idea/", "Total Size"]);
+    table.add_rows(rows);
+
+    table
+}
+
+fn used_resources_per_process(processes: &[ProcessUsage]) {
+    let rows = processes
+        .iter()
+        .map(|usage| {
+            vec![
+                format!("{}", usage.pid),
+                format!("{}", usage.use_case),
+                format!("{}", usage.stats.rss),
+                format!("{}", humantime::format_duration(usage.stats.uptime)),
+                usage
+                    .daemon_state
+                    .map(|state| state.identifier().to_string())
+                    .unwrap_or(String::from("-")),
+                usage.tool_version.clone().unwrap_or(String::from("-")),
+                usage.java_version.clone().unwrap_or(String::from("-")),
+                usage
+                    .stats
+                    .working_dir
+                    .as_ref()
+                    .map(|path| path.to_string_lossy().to_string())
+                    .unwrap_or(String::from("-")),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = new_table(vec![
+        "PID",
+        "What",
+        "RSS",
+        "Up for",
+        "State",
+        "Version",
+        "Java",
+        "Working directory",
+    ]);
+    table.set_width(140).add_rows(rows);
 
+    info!("");
     println!("{table}");
+}
 
+fn cleanup_outcome(resource: &MachineResource, outcome: &WipingOutcome) {
     info!("");
-    info!("Total resources ({}) : {:.1}", resource, &outcome.total_size);
+
+    if !outcome.stopped_processes.is_empty() {
+        stopped_processes(&outcome.stopped_processes);
+    }
+
+    if !outcome.protected_processes.is_empty() {
+        protected_processes(&outcome.protected_processes);
+    }
+
+    if outcome.removals.is_empty() {
+        outcome.failures.iter().for_each(|failure| warn!("Not wiped {failure}"));
+    } else {
+        path_removals(&outcome.removals);
+    }
+
+    info!("Reclaimed {} : {:.1}", resource, outcome.reclaimed);
     info!("");
+
+    if let Some(quarantine) = &outcome.quarantine {
+        quarantine_hint(quarantine, outcome.quarantined);
+    }
 }
 
-fn cleanup_outcome(resource: &MachineResource, outcome: &WipingOutcome) {
+// Paths not present in the first place are only counted, since most machines lack several of the wiped locations
+fn path_removals(removals: &[PathRemoval]) {
+    let not_present = removals
+        .iter()
+        .filter(|removal| removal.status == RemovalStatus::NotPresent)
+        .count();
+
+    if not_present > 0 {
+        info!("Skipped {not_present} target paths not present on this machine");
+        info!("");
+    }
+
+    let rows = removals
+        .iter()
+        .filter(|removal| removal.status.is_failure())
+        .map(|removal| {
+            vec![
+                format!("{}", removal.path.to_string_lossy()),
+                removal.status.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    if rows.is_empty() {
+        return;
+    }
+
+    let mut table = new_table(vec!["Not wiped", "Status"]);
+    table.add_rows(rows);
+
+    println!("{table}");
+    info!("");
+}
+
+fn quarantine_hint(quarantine: &str, quarantined: ByteUnit) {
+    info!("Moved {quarantined:.1} of wiped files to quarantine {quarantine}, disk space is only freed once purged");
+    info!("Undo with `gradle-wiper restore {quarantine}`, or free space with `gradle-wiper purge`");
+    info!("");
+}
+
+fn restoring_outcome(outcome: &RestoringOutcome) {
+    info!("");
+
+    outcome
+        .restored
+        .iter()
+        .for_each(|path| info!("Restored {}", path.to_string_lossy()));
+
+    outcome
+        .failures
+        .iter()
+        .for_each(|failure| warn!("Not restored {failure}"));
+
+    info!("");
+    info!(
+        "Restored {} of {} paths from quarantine {}",
+        outcome.restored.len(),
+        outcome.restored.len() + outcome.failures.len(),
+        outcome.quarantine
+    );
+    info!("");
+}
+
+fn stopped_processes(processes: &[StoppedProcess]) {
+    let rows = processes
+        .iter()
+        .map(|process| {
+            vec![
+                format!("{}", process.pid),
+                process.launcher.clone(),
+                process.shutdown.identifier().to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = new_table(vec!["PID", "Process", "Shutdown"]);
+    table.add_rows(rows);
+
+    println!("{table}");
+}
+
+fn protected_processes(processes: &[ProtectedProcess]) {
+    let rows = processes
+        .iter()
+        .map(|process| {
+            vec![
+                format!("{}", process.pid),
+                process.launcher.clone(),
+                process.reason.clone(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = new_table(vec!["PID", "Protected process", "Reason"]);
+    table.add_rows(rows);
+
+    println!("{table}");
+}
+
+fn simulated_cleanup(resource: &MachineResource, outcome: &DryRunOutcome) {
+    info!("");
+
+    let reclaimable = &outcome.resources;
+
+    if reclaimable.is_empty() {
+        info!("Nothing to reclaim from {resource}");
+        info!("");
+        return;
+    }
+
+    let rows = reclaimable
+        .iter()
+        .map(|res| {
+            vec![
+                format!("{}", res.target),
+                format!("{}", res.use_case),
+                format!("{}", res.amount),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = new_table(vec!["Target", "What", "Total Size"]);
+    table.add_rows(rows);
+
+    println!("{table}");
+
+    info!("");
+    info!("Would reclaim {} : {:.1} (dry-run)", resource, &outcome.total_size);
+    info!("");
+}
+
+fn pruning_outcome(resource: &MachineResource, outcome: &PruningOutcome) {
+    info!("");
+
+    if outcome.resources.is_empty() {
+        info!("Nothing to prune from {resource}");
+        info!("");
+        return;
+    }
+
+    let rows = outcome
+        .resources
+        .iter()
+        .map(|res| {
+            let removal = outcome.removals.iter().find(|removal| removal.path == res.path);
+            let status = match removal {
+                _ if res.kept => String::from("kept"),
+                Some(removal) => removal.status.to_string(),
+                None => String::from("removed"),
+            };
+            vec![
+                format!("{}", res.path.to_string_lossy()),
+                format!("{}", res.use_case),
+                format!("{}", res.amount),
+                status,
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = new_table(vec!["Target", "What", "Total Size", "Status"]);
+    table.add_rows(rows);
+
+    println!("{table}");
+
     info!("");
     info!("Reclaimed {} : {:.1}", resource, outcome.reclaimed);
     info!("");
+
+    if let Some(quarantine) = &outcome.quarantine {
+        quarantine_hint(quarantine, outcome.quarantined);
+    }
+}
+
+// Rendered along with the prompts, away from the report written to stdout
+pub fn show_evaluation_preview(outcome: &EvaluationOutcome) {
+    info!("");
+    info!("{}", used_resources_table(outcome));
+
+    if !outcome.projects.is_empty() {
+        info!("");
+        info!("{}", used_resources_per_project_table(&outcome.projects));
+    }
+
+    info!("");
+    info!(
+        "Total resources ({}) : {:.1}",
+        MachineResource::DiskSpace,
+        &outcome.total_size
+    );
+    info!("");
+}
+
+pub fn show_preflight_summary(summary: &[(UseCase, usize, ByteUnit)], total_size: ByteUnit) {
+    let rows = summary
+        .iter()
+        .map(|(use_case, paths, amount)| vec![use_case.to_string(), paths.to_string(), format!("{amount}")])
+        .collect::<Vec<_>>();
+
+    let mut table = new_table(vec!["What", "Paths", "Estimated Size"]);
+    table.add_rows(rows);
+
+    // Rendered along with the prompt, away from the report written to stdout
+    info!("");
+    info!("{table}");
+    info!("");
+    info!("Estimated disk space to reclaim : {:.1}", total_size);
+    info!("");
+}
+
+fn new_table(header: Vec<&str>) -> Table {
+    let mut table = Table::new();
+
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_width(120)
+        .set_header(header);
+
+    table
 }
@@ -0,0 +1,307 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::cli::feedback;
+use crate::core::models::{
+    DiskCached, DryRunOutcome, EvaluationOutcome, ExecutionOptions, ExecutionOutcome, MachineResource,
+    ProjectLevelDiskCache, UseCase, UserLevelDiskCache, WipeAction, WipeSelection,
+};
+use crate::core::wiper;
+use anyhow::bail;
+use dialoguer::{Confirm, MultiSelect};
+use log::info;
+use std::io::IsTerminal;
+use ubyte::ByteUnit;
+
+// Returns no options when users give up on wiping
+pub fn select_wipe_targets(action: &WipeAction, options: ExecutionOptions) -> anyhow::Result<Option<ExecutionOptions>> {
+    if !matches!(action, WipeAction::SelectiveWipe) {
+        return Ok(Some(options));
+    }
+
+    if !std::io::stdin().is_terminal() {
+        bail!("Picking caches to wipe interactively requires a terminal");
+    }
+
+    let evaluation_options = ExecutionOptions {
+        gradle_user_home: options.gradle_user_home.clone(),
+        project_discovery: options.project_discovery.clone(),
+        per_project: true,
+        ..ExecutionOptions::default()
+    };
+
+    let outcome = wiper::execute(&MachineResource::DiskSpace, WipeAction::Evaluate, &evaluation_options)?;
+
+    let ExecutionOutcome::Evaluation(evaluation) = outcome else {
+        bail!("Cannot evaluate disk space before wiping");
+    };
+
+    feedback::show_evaluation_preview(&evaluation);
+
+    let categories = wipeable_caches(&evaluation);
+    let labels = categories
+        .iter()
+        .map(|(cached, amount)| format!("{cached} ({amount:.1})"))
+        .collect::<Vec<_>>();
+
+    let picked = MultiSelect::new()
+        .with_prompt("Which caches should be wiped? (space to select, enter to confirm)")
+        .items(&labels)
+        .interact()?;
+
+    let caches = picked.into_iter().map(|index| categories[index].0).collect::<Vec<_>>();
+    let mut projects = evaluation
+        .projects
+        .iter()
+        .map(|usage| usage.project.clone())
+        .collect::<Vec<_>>();
+
+    if caches.iter().any(|cached| matches!(cached, DiskCached::Standalone(_))) && !projects.is_empty() {
+        let labels = evaluation
+            .projects
+            .iter()
+            .map(|usage| format!("{} ({:.1})", usage.project.to_string_lossy(), usage.total()))
+            .collect::<Vec<_>>();
+
+        let picked = MultiSelect::new()
+            .with_prompt("Which projects should be wiped?")
+            .items(&labels)
+            .defaults(&vec![true; labels.len()])
+            .interact()?;
+
+        projects = picked.into_iter().map(|index| projects[index].clone()).collect();
+    }
+
+    let selection = WipeSelection { caches, projects };
+
+    if selection.caches.is_empty() {
+        info!("No caches were selected, nothing to wipe");
+        return Ok(None);
+    }
+
+    info!("");
+    info!(
+        "Projected reclaimed disk space : {:.1}",
+        projected_reclaim(&evaluation, &selection)
+    );
+    info!("");
+
+    let confirmed = Confirm::new()
+        .with_prompt("Wipe the selected caches?")
+        .default(false)
+        .interact()?;
+
+    if !confirmed {
+        return Ok(None);
+    }
+
+    Ok(Some(ExecutionOptions {
+        selection: Some(selection),
+        ..options
+    }))
+}
+
+// Wiping disk space can't be undone, hence what is about to be removed is summarized and confirmed first
+pub fn confirm_wipe(
+    resource: &MachineResource,
+    action: &WipeAction,
+    options: &ExecutionOptions,
+    assume_yes: bool,
+) -> anyhow::Result<bool> {
+    let wipes_disk = !matches!(resource, MachineResource::RamMemory)
+        && !matches!(
+            action,
+            WipeAction::Evaluate | WipeAction::SelectiveWipe | WipeAction::RestoreQuarantine
+        );
+
+    if !wipes_disk || options.dry_run || assume_yes {
+        return Ok(true);
+    }
+
+    if !std::io::stdin().is_terminal() {
+        if matches!(action, WipeAction::DeepWipe) {
+            bail!("Refusing to deep wipe {resource} without confirmation, pass --yes when running non-interactively");
+        }
+
+        return Ok(true);
+    }
+
+    let preview_options = ExecutionOptions {
+        dry_run: true,
+        stop_daemons: false,
+        ..options.clone()
+    };
+
+    let ExecutionOutcome::DryRun(preview) = wiper::execute(&MachineResource::DiskSpace, *action, &preview_options)?
+    else {
+        bail!("Cannot preview the {action} of disk space");
+    };
+
+    if preview.resources.is_empty() {
+        return Ok(true);
+    }
+
+    feedback::show_preflight_summary(&summarize_per_category(&preview), preview.total_size);
+
+    let confirmed = Confirm::new()
+        .with_prompt(format!("Proceed with the {action} of {resource}?"))
+        .default(false)
+        .interact()?;
+
+    Ok(confirmed)
+}
+
+fn summarize_per_category(preview: &DryRunOutcome) -> Vec<(UseCase, usize, ByteUnit)> {
+    preview.resources.iter().fold(Vec::new(), |mut summary, resource| {
+        match summary
+            .iter_mut()
+            .find(|(use_case, _, _)| *use_case == resource.use_case)
+        {
+            Some((_, paths, amount)) => {
+                *paths += 1;
+                *amount += resource.amount;
+            },
+            None => summary.push((resource.use_case, 1, resource.amount)),
+        }
+
+        summary
+    })
+}
+
+// Other files on Gradle home are not tied to any folder we can wipe as a whole
+fn wipeable_caches(evaluation: &EvaluationOutcome) -> Vec<(DiskCached, ByteUnit)> {
+    let shared = evaluation
+        .resources
+        .iter()
+        .filter_map(|resource| match resource.use_case {
+            UseCase::Disk(DiskCached::Shared(UserLevelDiskCache::GradleOtherCaches)) => None,
+            UseCase::Disk(cached @ DiskCached::Shared(_)) => Some((cached, resource.amount)),
+            _ => None,
+        });
+
+    let standalone = ProjectLevelDiskCache::ALL.into_iter().map(|cached| {
+        let amount = evaluation
+            .projects
+            .iter()
+            .fold(ByteUnit::from(0), |total, usage| total + usage.amount(cached));
+        (DiskCached::Standalone(cached), amount)
+    });
+
+    shared
+        .chain(standalone)
+        .filter(|(_, amount)| *amount > ByteUnit::from(0))
+        .collect::<Vec<_>>()
+}
+
+fn projected_reclaim(evaluation: &EvaluationOutcome, selection: &WipeSelection) -> ByteUnit {
+    selection
+        .caches
+        .iter()
+        .map(|cached| match cached {
+            DiskCached::Shared(_) => evaluation
+                .resources
+                .iter()
+                .filter(|resource| resource.use_case == UseCase::Disk(*cached))
+                .fold(ByteUnit::from(0), |total, resource| total + resource.amount),
+            DiskCached::Standalone(project_level) => evaluation
+                .projects
+                .iter()
+                .filter(|usage| selection.projects.contains(&usage.project))
+                .fold(ByteUnit::from(0), |total, usage| total + usage.amount(*project_level)),
+        })
+        .fold(ByteUnit::from(0), |total, amount| total + amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::interactive::{projected_reclaim, summarize_per_category, wipeable_caches};
+    use crate::core::models::{
+        AllocatedResource, DiskCached, DryRunOutcome, EvaluationOutcome, ProjectLevelDiskCache, ProjectUsage,
+        ReclaimableResource, UseCase, UserLevelDiskCache, WipeSelection, WipeTarget,
+    };
+    use std::path::PathBuf;
+    use ubyte::{ByteUnit, ToByteUnit};
+
+    fn evaluation() -> EvaluationOutcome {
+        let resources = vec![
+            AllocatedResource::new(UseCase::from(UserLevelDiskCache::GradleBuildCaching), 4.kilobytes()),
+            AllocatedResource::new(UseCase::from(UserLevelDiskCache::GradleOtherCaches), 3.kilobytes()),
+            AllocatedResource::new(UseCase::from(UserLevelDiskCache::KonanCaches), 0.kilobytes()),
+            AllocatedResource::new(UseCase::from(ProjectLevelDiskCache::BuildOutput), 5.kilobytes()),
+        ];
+
+        let projects = vec![
+            ProjectUsage::new(PathBuf::from("/dev/app"), 3.kilobytes(), 2.kilobytes(), 0.kilobytes()),
+            ProjectUsage::new(PathBuf::from("/dev/lib"), 2.kilobytes(), 1.kilobytes(), 0.kilobytes()),
+        ];
+
+        EvaluationOutcome::new(resources, 12.kilobytes()).with_projects(projects)
+    }
+
+    #[test]
+    fn should_offer_only_wipeable_caches_taking_space() {
+        let caches = wipeable_caches(&evaluation());
+
+        let expected = vec![
+            (
+                DiskCached::Shared(UserLevelDiskCache::GradleBuildCaching),
+                4.kilobytes(),
+            ),
+            (
+                DiskCached::Standalone(ProjectLevelDiskCache::BuildOutput),
+                5.kilobytes(),
+            ),
+            (
+                DiskCached::Standalone(ProjectLevelDiskCache::GradleMetadata),
+                3.kilobytes(),
+            ),
+        ];
+
+        assert_eq!(caches, expected);
+    }
+
+    #[test]
+    fn should_project_reclaimed_space_for_selected_caches_and_projects() {
+        let selection = WipeSelection {
+            caches: vec![
+                DiskCached::Shared(UserLevelDiskCache::GradleBuildCaching),
+                DiskCached::Standalone(ProjectLevelDiskCache::BuildOutput),
+            ],
+            projects: vec![PathBuf::from("/dev/lib")],
+        };
+
+        let reclaimed = projected_reclaim(&evaluation(), &selection);
+
+        assert_eq!(reclaimed, ByteUnit::from(6000));
+    }
+
+    #[test]
+    fn should_summarize_paths_to_remove_per_category() {
+        let build_cache = UseCase::from(UserLevelDiskCache::GradleBuildCaching);
+        let build_output = UseCase::from(ProjectLevelDiskCache::BuildOutput);
+
+        let resources = vec![
+            ReclaimableResource::new(
+                WipeTarget::Path(PathBuf::from("/dev/app/build")),
+                build_output,
+                3.kilobytes(),
+            ),
+            ReclaimableResource::new(
+                WipeTarget::Path(PathBuf::from("/home/.gradle/caches")),
+                build_cache,
+                4.kilobytes(),
+            ),
+            ReclaimableResource::new(
+                WipeTarget::Path(PathBuf::from("/dev/lib/build")),
+                build_output,
+                2.kilobytes(),
+            ),
+        ];
+
+        let summary = summarize_per_category(&DryRunOutcome::new(resources, 9.kilobytes()));
+
+        let expected = vec![(build_output, 2, 5.kilobytes()), (build_cache, 1, 4.kilobytes())];
+
+        assert_eq!(summary, expected);
+    }
+}
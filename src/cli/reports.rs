@@ -0,0 +1,650 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::models::{
+    AllocatedResource, DryRunOutcome, EvaluationOutcome, ExecutionOutcome, JvmMemoryUsage, MachineResource,
+    PathRemoval, ProcessUsage, ProjectUsage, ProtectedProcess, PrunedResource, PruningOutcome, ReclaimableResource,
+    RemovalStatus, StoppedProcess, WipeTarget, WipingOutcome,
+};
+use serde::Serialize;
+
+// Bump whenever fields are renamed or removed; adding new fields is backwards-compatible
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Report {
+    schema_version: u32,
+    resource: &'static str,
+    #[serde(flatten)]
+    outcome: OutcomeReport,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "outcome", rename_all = "kebab-case")]
+enum OutcomeReport {
+    Evaluation {
+        total_bytes: u64,
+        resources: Vec<ResourceReport>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        projects: Vec<ProjectReport>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        jvm_memory: Vec<JvmMemoryReport>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        processes: Vec<ProcessReport>,
+    },
+    Wiping {
+        reclaimed_bytes: u64,
+        breakdown: Vec<ResourceReport>,
+        failures: Vec<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        stopped_processes: Vec<StoppedProcessReport>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        protected_processes: Vec<ProtectedProcessReport>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        quarantine: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        quarantined_bytes: Option<u64>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        paths: Vec<PathReport>,
+    },
+    DryRun {
+        total_bytes: u64,
+        targets: Vec<TargetReport>,
+    },
+    Pruning {
+        reclaimed_bytes: u64,
+        kept: Vec<PrunedReport>,
+        removed: Vec<PrunedReport>,
+        failures: Vec<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        quarantine: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        quarantined_bytes: Option<u64>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        paths: Vec<PathReport>,
+    },
+    Restoring {
+        quarantine: String,
+        restored: Vec<String>,
+        failures: Vec<String>,
+    },
+    Combined {
+        sections: Vec<SectionReport>,
+        totals: Vec<TotalReport>,
+    },
+}
+
+#[derive(Serialize)]
+struct TotalReport {
+    resource: &'static str,
+    bytes: u64,
+}
+
+#[derive(Serialize)]
+struct SectionReport {
+    resource: &'static str,
+    #[serde(flatten)]
+    outcome: OutcomeReport,
+}
+
+#[derive(Serialize)]
+struct ResourceReport {
+    category: &'static str,
+    description: String,
+    bytes: u64,
+}
+
+#[derive(Serialize)]
+struct ProjectReport {
+    path: String,
+    build_output_bytes: u64,
+    gradle_metadata_bytes: u64,
+    idea_metadata_bytes: u64,
+    total_bytes: u64,
+}
+
+#[derive(Serialize)]
+struct JvmMemoryReport {
+    category: &'static str,
+    description: String,
+    heap_used_bytes: u64,
+    heap_committed_bytes: u64,
+    metaspace_used_bytes: u64,
+    metaspace_committed_bytes: u64,
+}
+
+#[derive(Serialize)]
+struct ProcessReport {
+    pid: u32,
+    category: &'static str,
+    description: String,
+    rss_bytes: u64,
+    uptime_seconds: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    daemon_state: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    java_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    working_dir: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PrunedReport {
+    category: &'static str,
+    description: String,
+    path: String,
+    bytes: u64,
+}
+
+#[derive(Serialize)]
+struct PathReport {
+    path: String,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remaining_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct StoppedProcessReport {
+    pid: u32,
+    launcher: String,
+    shutdown: &'static str,
+}
+
+#[derive(Serialize)]
+struct ProtectedProcessReport {
+    pid: u32,
+    launcher: String,
+    reason: String,
+}
+
+#[derive(Serialize)]
+struct TargetReport {
+    category: &'static str,
+    description: String,
+    bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pid: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    launcher: Option<String>,
+}
+
+pub fn to_json(resource: &MachineResource, outcome: &ExecutionOutcome) -> anyhow::Result<String> {
+    let report = Report::new(resource, outcome);
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+pub fn to_yaml(resource: &MachineResource, outcome: &ExecutionOutcome) -> anyhow::Result<String> {
+    let report = Report::new(resource, outcome);
+    Ok(serde_yaml_ng::to_string(&report)?)
+}
+
+impl Report {
+    fn new(resource: &MachineResource, outcome: &ExecutionOutcome) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            resource: resource.identifier(),
+            outcome: OutcomeReport::from(outcome),
+        }
+    }
+}
+
+impl From<&ExecutionOutcome> for OutcomeReport {
+    fn from(value: &ExecutionOutcome) -> Self {
+        match value {
+            ExecutionOutcome::Evaluation(evaluation) => OutcomeReport::from(evaluation),
+            ExecutionOutcome::Wiping(wiping) => OutcomeReport::from(wiping),
+            ExecutionOutcome::DryRun(dry_run) => OutcomeReport::from(dry_run),
+            ExecutionOutcome::Pruning(pruning) => OutcomeReport::from(pruning),
+            ExecutionOutcome::Restoring(restoring) => OutcomeReport::Restoring {
+                quarantine: restoring.quarantine.clone(),
+                restored: restoring
+                    .restored
+                    .iter()
+                    .map(|path| path.to_string_lossy().to_string())
+                    .collect(),
+                failures: restoring.failures.clone(),
+            },
+            ExecutionOutcome::Combined(combined) => OutcomeReport::Combined {
+                sections: combined
+                    .sections
+                    .iter()
+                    .map(|(resource, outcome)| SectionReport {
+                        resource: resource.identifier(),
+                        outcome: OutcomeReport::from(outcome),
+                    })
+                    .collect(),
+                totals: combined
+                    .totals()
+                    .into_iter()
+                    .map(|(resource, amount)| TotalReport {
+                        resource: resource.identifier(),
+                        bytes: amount.as_u64(),
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<&EvaluationOutcome> for OutcomeReport {
+    fn from(value: &EvaluationOutcome) -> Self {
+        OutcomeReport::Evaluation {
+            total_bytes: value.total_size.as_u64(),
+            resources: value.resources.iter().map(ResourceReport::from).collect(),
+            projects: value.projects.iter().map(ProjectReport::from).collect(),
+            jvm_memory: value.jvm_memory.iter().map(JvmMemoryReport::from).collect(),
+            processes: value.processes.iter().map(ProcessReport::from).collect(),
+        }
+    }
+}
+
+impl From<&WipingOutcome> for OutcomeReport {
+    fn from(value: &WipingOutcome) -> Self {
+        OutcomeReport::Wiping {
+            reclaimed_bytes: value.reclaimed.as_u64(),
+            breakdown: value.breakdown.iter().map(ResourceReport::from).collect(),
+            failures: value.failures.clone(),
+            stopped_processes: value.stopped_processes.iter().map(StoppedProcessReport::from).collect(),
+            protected_processes: value
+                .protected_processes
+                .iter()
+                .map(ProtectedProcessReport::from)
+                .collect(),
+            quarantine: value.quarantine.clone(),
+            quarantined_bytes: value.quarantine.as_ref().map(|_| value.quarantined.as_u64()),
+            paths: value.removals.iter().map(PathReport::from).collect(),
+        }
+    }
+}
+
+impl From<&DryRunOutcome> for OutcomeReport {
+    fn from(value: &DryRunOutcome) -> Self {
+        OutcomeReport::DryRun {
+            total_bytes: value.total_size.as_u64(),
+            targets: value.resources.iter().map(TargetReport::from).collect(),
+        }
+    }
+}
+
+impl From<&PruningOutcome> for OutcomeReport {
+    fn from(value: &PruningOutcome) -> Self {
+        let (kept, removed): (Vec<_>, Vec<_>) = value.resources.iter().partition(|resource| resource.kept);
+
+        OutcomeReport::Pruning {
+            reclaimed_bytes: value.reclaimed.as_u64(),
+            kept: kept.into_iter().map(PrunedReport::from).collect(),
+            removed: removed.into_iter().map(PrunedReport::from).collect(),
+            failures: value.failures.clone(),
+            quarantine: value.quarantine.clone(),
+            quarantined_bytes: value.quarantine.as_ref().map(|_| value.quarantined.as_u64()),
+            paths: value.removals.iter().map(PathReport::from).collect(),
+        }
+    }
+}
+
+impl From<&AllocatedResource> for ResourceReport {
+    fn from(value: &AllocatedResource) -> Self {
+        Self {
+            category: value.use_case.identifier(),
+            description: value.use_case.to_string(),
+            bytes: value.amount.as_u64(),
+        }
+    }
+}
+
+impl From<&ProjectUsage> for ProjectReport {
+    fn from(value: &ProjectUsage) -> Self {
+        Self {
+            path: value.project.to_string_lossy().to_string(),
+            build_output_bytes: value.build_output.as_u64(),
+            gradle_metadata_bytes: value.gradle_metadata.as_u64(),
+            idea_metadata_bytes: value.idea_metadata.as_u64(),
+            total_bytes: value.total().as_u64(),
+        }
+    }
+}
+
+impl From<&JvmMemoryUsage> for JvmMemoryReport {
+    fn from(value: &JvmMemoryUsage) -> Self {
+        Self {
+            category: value.use_case.identifier(),
+            description: value.use_case.to_string(),
+            heap_used_bytes: value.heap_used.as_u64(),
+            heap_committed_bytes: value.heap_committed.as_u64(),
+            metaspace_used_bytes: value.metaspace_used.as_u64(),
+            metaspace_committed_bytes: value.metaspace_committed.as_u64(),
+        }
+    }
+}
+
+impl From<&ProcessUsage> for ProcessReport {
+    fn from(value: &ProcessUsage) -> Self {
+        Self {
+            pid: value.pid,
+            category: value.use_case.identifier(),
+            description: value.use_case.to_string(),
+            rss_bytes: value.stats.rss.as_u64(),
+            uptime_seconds: value.stats.uptime.as_secs(),
+            daemon_state: value.daemon_state.map(|state| state.identifier()),
+            tool_version: value.tool_version.clone(),
+            java_version: value.java_version.clone(),
+            working_dir: value
+                .stats
+                .working_dir
+                .as_ref()
+                .map(|path| path.to_string_lossy().to_string()),
+        }
+    }
+}
+
+impl From<&PrunedResource> for PrunedReport {
+    fn from(value: &PrunedResource) -> Self {
+        Self {
+            category: value.use_case.identifier(),
+            description: value.use_case.to_string(),
+            path: value.path.to_string_lossy().to_string(),
+            bytes: value.amount.as_u64(),
+        }
+    }
+}
+
+impl From<&PathRemoval> for PathReport {
+    fn from(value: &PathRemoval) -> Self {
+        let (remaining_bytes, error) = match &value.status {
+            RemovalStatus::PartiallyRemoved(remaining) => (Some(remaining.as_u64()), None),
+            RemovalStatus::Failed(reason) => (None, Some(reason.clone())),
+            _ => (None, None),
+        };
+
+        Self {
+            path: value.path.to_string_lossy().to_string(),
+            status: value.status.identifier(),
+            remaining_bytes,
+            error,
+        }
+    }
+}
+
+impl From<&StoppedProcess> for StoppedProcessReport {
+    fn from(value: &StoppedProcess) -> Self {
+        Self {
+            pid: value.pid,
+            launcher: value.launcher.clone(),
+            shutdown: value.shutdown.identifier(),
+        }
+    }
+}
+
+impl From<&ProtectedProcess> for ProtectedProcessReport {
+    fn from(value: &ProtectedProcess) -> Self {
+        Self {
+            pid: value.pid,
+            launcher: value.launcher.clone(),
+            reason: value.reason.clone(),
+        }
+    }
+}
+
+impl From<&ReclaimableResource> for TargetReport {
+    fn from(value: &ReclaimableResource) -> Self {
+        let (path, pid, launcher) = match &value.target {
+            WipeTarget::Path(path) => (Some(path.to_string_lossy().to_string()), None, None),
+            WipeTarget::Process { pid, launcher } => (None, Some(*pid), Some(launcher.clone())),
+        };
+
+        Self {
+            category: value.use_case.identifier(),
+            description: value.use_case.to_string(),
+            bytes: value.amount.as_u64(),
+            path,
+            pid,
+            launcher,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::reports::to_json;
+    use crate::core::models::{
+        AllocatedResource, CombinedOutcome, EvaluationOutcome, ExecutionOutcome, MachineResource, MemoryCached,
+        PathRemoval, PrunedResource, PruningOutcome, RemovalStatus, UseCase, UserLevelDiskCache, WipingOutcome,
+    };
+    use std::path::PathBuf;
+    use ubyte::ToByteUnit;
+
+    #[test]
+    fn should_serialize_evaluation_as_json() {
+        let use_case = UseCase::from(UserLevelDiskCache::GradleBuildCaching);
+        let resources = vec![AllocatedResource::new(use_case, 2.kilobytes())];
+        let outcome = ExecutionOutcome::Evaluation(EvaluationOutcome::new(resources, 2.kilobytes()));
+
+        let json = to_json(&MachineResource::DiskSpace, &outcome).expect("Cannot serialize outcome");
+
+        let expected = r#"{
+  "schema_version": 1,
+  "resource": "disk",
+  "outcome": "evaluation",
+  "total_bytes": 2000,
+  "resources": [
+    {
+      "category": "build-cache",
+      "description": "Gradle Build Caches",
+      "bytes": 2000
+    }
+  ]
+}"#;
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn should_serialize_wiping_as_json() {
+        let use_case = UseCase::from(UserLevelDiskCache::MavenLocalRepository);
+        let breakdown = vec![AllocatedResource::new(use_case, 1.kilobytes())];
+        let failures = vec![String::from("/home/.m2 : Permission denied (os error 13)")];
+        let outcome = ExecutionOutcome::Wiping(WipingOutcome::new(1.kilobytes(), breakdown, failures));
+
+        let json = to_json(&MachineResource::DiskSpace, &outcome).expect("Cannot serialize outcome");
+
+        let expected = r#"{
+  "schema_version": 1,
+  "resource": "disk",
+  "outcome": "wiping",
+  "reclaimed_bytes": 1000,
+  "breakdown": [
+    {
+      "category": "maven-local",
+      "description": "Maven local repository",
+      "bytes": 1000
+    }
+  ],
+  "failures": [
+    "/home/.m2 : Permission denied (os error 13)"
+  ]
+}"#;
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn should_serialize_quarantined_wiping_as_json() {
+        let outcome = ExecutionOutcome::Wiping(
+            WipingOutcome::new(0.bytes(), vec![], vec![])
+                .with_quarantine(Some(String::from("20260105T093012Z")), 3.kilobytes()),
+        );
+
+        let json = to_json(&MachineResource::DiskSpace, &outcome).expect("Cannot serialize outcome");
+
+        let expected = r#"{
+  "schema_version": 1,
+  "resource": "disk",
+  "outcome": "wiping",
+  "reclaimed_bytes": 0,
+  "breakdown": [],
+  "failures": [],
+  "quarantine": "20260105T093012Z",
+  "quarantined_bytes": 3000
+}"#;
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn should_serialize_wiping_with_path_statuses_as_json() {
+        let removals = vec![
+            PathRemoval::new(PathBuf::from("/home/.gradle/caches"), RemovalStatus::Removed),
+            PathRemoval::new(PathBuf::from("/home/.konan"), RemovalStatus::NotPresent),
+            PathRemoval::new(
+                PathBuf::from("/home/.m2"),
+                RemovalStatus::PartiallyRemoved(1.kilobytes()),
+            ),
+        ];
+        let failures = removals.iter().filter_map(PathRemoval::failure).collect();
+        let outcome =
+            ExecutionOutcome::Wiping(WipingOutcome::new(2.kilobytes(), vec![], failures).with_removals(removals));
+
+        let json = to_json(&MachineResource::DiskSpace, &outcome).expect("Cannot serialize outcome");
+
+        let expected = r#"{
+  "schema_version": 1,
+  "resource": "disk",
+  "outcome": "wiping",
+  "reclaimed_bytes": 2000,
+  "breakdown": [],
+  "failures": [
+    "/home/.m2 : partially removed, 1kB left"
+  ],
+  "paths": [
+    {
+      "path": "/home/.gradle/caches",
+      "status": "removed"
+    },
+    {
+      "path": "/home/.konan",
+      "status": "not-present"
+    },
+    {
+      "path": "/home/.m2",
+      "status": "partially-removed",
+      "remaining_bytes": 1000
+    }
+  ]
+}"#;
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn should_serialize_pruning_as_json() {
+        let use_case = UseCase::from(UserLevelDiskCache::GradleDistributions);
+        let resources = vec![
+            PrunedResource::new(PathBuf::from("/dists/gradle-8.7-bin"), use_case, 3.kilobytes(), true),
+            PrunedResource::new(PathBuf::from("/dists/gradle-8.2-bin"), use_case, 2.kilobytes(), false),
+        ];
+        let outcome = ExecutionOutcome::Pruning(PruningOutcome::new(resources, 2.kilobytes(), vec![]));
+
+        let json = to_json(&MachineResource::DiskSpace, &outcome).expect("Cannot serialize outcome");
+
+        let expected = r#"{
+  "schema_version": 1,
+  "resource": "disk",
+  "outcome": "pruning",
+  "reclaimed_bytes": 2000,
+  "kept": [
+    {
+      "category": "distributions",
+      "description": "Gradle Distributions",
+      "path": "/dists/gradle-8.7-bin",
+      "bytes": 3000
+    }
+  ],
+  "removed": [
+    {
+      "category": "distributions",
+      "description": "Gradle Distributions",
+      "path": "/dists/gradle-8.2-bin",
+      "bytes": 2000
+    }
+  ],
+  "failures": []
+}"#;
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn should_serialize_combined_outcome_as_json() {
+        let ram_use_case = UseCase::from(MemoryCached::GradleBuildDaemon);
+        let disk_use_case = UseCase::from(UserLevelDiskCache::GradleBuildCaching);
+        let ram_breakdown = vec![AllocatedResource::new(ram_use_case, 3.kilobytes())];
+        let disk_breakdown = vec![AllocatedResource::new(disk_use_case, 2.kilobytes())];
+
+        let sections = vec![
+            (
+                MachineResource::RamMemory,
+                ExecutionOutcome::Wiping(WipingOutcome::new(3.kilobytes(), ram_breakdown, vec![])),
+            ),
+            (
+                MachineResource::DiskSpace,
+                ExecutionOutcome::Wiping(WipingOutcome::new(2.kilobytes(), disk_breakdown, vec![])),
+            ),
+        ];
+        let outcome = ExecutionOutcome::Combined(CombinedOutcome::new(sections));
+
+        let json = to_json(&MachineResource::DiskSpace, &outcome).expect("Cannot serialize outcome");
+
+        let expected = r#"{
+  "schema_version": 1,
+  "resource": "disk",
+  "outcome": "combined",
+  "sections": [
+    {
+      "resource": "ram",
+      "outcome": "wiping",
+      "reclaimed_bytes": 3000,
+      "breakdown": [
+        {
+          "category": "gradle-daemon",
+          "description": "Gradle Daemon",
+          "bytes": 3000
+        }
+      ],
+      "failures": []
+    },
+    {
+      "resource": "disk",
+      "outcome": "wiping",
+      "reclaimed_bytes": 2000,
+      "breakdown": [
+        {
+          "category": "build-cache",
+          "description": "Gradle Build Caches",
+          "bytes": 2000
+        }
+      ],
+      "failures": []
+    }
+  ],
+  "totals": [
+    {
+      "resource": "ram",
+      "bytes": 3000
+    },
+    {
+      "resource": "disk",
+      "bytes": 2000
+    }
+  ]
+}"#;
+
+        assert_eq!(json, expected);
+    }
+}
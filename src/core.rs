@@ -1,6 +1,7 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+mod decoding;
 mod disk;
 pub mod models;
 mod ram;
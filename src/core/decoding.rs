@@ -0,0 +1,85 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+// Reads values the way Gradle writes its internal state files, through java.io.DataOutputStream or its own
+// encoders built on top of it : big-endian numbers, booleans as one byte and strings prefixed by their length
+// https://github.com/gradle/gradle/blob/master/platforms/core-runtime/serialization/src/main/java/org/gradle/internal/serialize/AbstractDecoder.java
+
+use anyhow::{Context, ensure};
+
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub fn skip(&mut self, count: usize) -> anyhow::Result<()> {
+        self.read_bytes(count).map(|_| ())
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    pub fn read_byte(&mut self) -> anyhow::Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_boolean(&mut self) -> anyhow::Result<bool> {
+        match self.read_byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => anyhow::bail!("unexpected boolean {other} at {}", self.position - 1),
+        }
+    }
+
+    pub fn read_int(&mut self) -> anyhow::Result<i32> {
+        Ok(i32::from_be_bytes(self.read_bytes(4)?.try_into()?))
+    }
+
+    pub fn read_long(&mut self) -> anyhow::Result<i64> {
+        Ok(i64::from_be_bytes(self.read_bytes(8)?.try_into()?))
+    }
+
+    // Counts prefix collections, so they can't exceed what is left to read
+    pub fn read_count(&mut self) -> anyhow::Result<usize> {
+        let count = self.read_int()?;
+        let count = usize::try_from(count).with_context(|| format!("unexpected count {count}"))?;
+        ensure!(count <= self.bytes.len() - self.position, "unexpected count {count}");
+        Ok(count)
+    }
+
+    // Strings are written with DataOutputStream.writeUTF, which only differs from UTF-8 for unusual characters
+    pub fn read_string(&mut self) -> anyhow::Result<String> {
+        let length = u16::from_be_bytes(self.read_bytes(2)?.try_into()?) as usize;
+        Ok(String::from_utf8_lossy(self.read_bytes(length)?).to_string())
+    }
+
+    pub fn read_nullable_string(&mut self) -> anyhow::Result<Option<String>> {
+        match self.read_boolean()? {
+            true => self.read_string().map(Some),
+            false => Ok(None),
+        }
+    }
+
+    pub fn read_binary(&mut self) -> anyhow::Result<&'a [u8]> {
+        let length = self.read_count()?;
+        self.read_bytes(length)
+    }
+
+    fn read_bytes(&mut self, count: usize) -> anyhow::Result<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(count)
+            .filter(|end| *end <= self.bytes.len())
+            .with_context(|| format!("unexpected end of data at {}", self.position))?;
+
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+}
@@ -2,8 +2,12 @@
 // SPDX-License-Identifier: MIT
 
 mod locations;
+mod locks;
 mod operations;
+mod pruning;
+mod quarantine;
 mod resources;
+mod retention;
 
 use directories::BaseDirs;
 pub use locations::find_all_gradle_projects;
@@ -11,11 +15,28 @@ pub use locations::find_associated_filepaths;
 pub use locations::find_gradle_home;
 pub use locations::find_konan_caches;
 pub use locations::find_maven_local_repository;
+pub use locks::find_lock_owners;
 pub use operations::cleanup_resources;
+pub use pruning::find_installed_distributions;
+pub use pruning::find_installed_toolchains;
+pub use pruning::find_referenced_distributions;
+pub use pruning::find_requested_toolchains;
+pub use quarantine::discard_purged_entries;
+pub use quarantine::find_quarantined_entries;
+pub use quarantine::locate_quarantine_dir;
+pub use quarantine::quarantine_resources;
+pub use quarantine::restore_quarantine;
+pub use resources::disk_usage;
 pub use resources::resources_used_by_gradle_home;
 pub use resources::resources_used_by_gradle_projects;
 pub use resources::resources_used_by_konan;
 pub use resources::resources_used_by_maven_local_repository;
+pub use resources::resources_used_per_gradle_project_breakdown;
+pub use retention::entries_for_paths;
+pub use retention::entries_within_folder;
+pub use retention::find_cache_entries;
+pub use retention::find_stale_cache_entries;
+pub use retention::select_least_recently_used;
 use std::path::PathBuf;
 
 pub fn user_home_locator() -> PathBuf {
@@ -1,16 +1,75 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::disk::user_home_locator;
-use crate::core::models::{DiskCached, ProjectLevelDiskCache};
+use crate::core::models::{DiskCached, ProjectDiscovery, ProjectLevelDiskCache};
 use cached::proc_macro::cached;
+use glob::Pattern;
 use itertools::Itertools;
 use log::debug;
+use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::{DirEntry, WalkDir};
 
-pub fn find_gradle_home(user_home: &Path) -> PathBuf {
-    user_home.to_path_buf().join(".gradle")
+// https://docs.gradle.org/current/userguide/directory_layout.html#dir:gradle_user_home
+pub fn find_gradle_home(user_home: &Path, overridden: Option<&Path>) -> PathBuf {
+    let gradle_opts = std::env::var("GRADLE_OPTS").ok();
+    let gradle_user_home_env = std::env::var("GRADLE_USER_HOME").ok();
+    resolve_gradle_home(user_home, overridden, gradle_opts, gradle_user_home_env)
+}
+
+fn resolve_gradle_home(
+    user_home: &Path,
+    overridden: Option<&Path>,
+    gradle_opts: Option<String>,
+    gradle_user_home_env: Option<String>,
+) -> PathBuf {
+    let default_gradle_home = user_home.join(".gradle");
+
+    let resolved = overridden
+        .map(|path| path.to_string_lossy().to_string())
+        .or_else(|| gradle_opts.as_deref().and_then(gradle_user_home_from_jvm_options))
+        .or(gradle_user_home_env)
+        .filter(|raw_path| !raw_path.trim().is_empty())
+        .or_else(|| gradle_user_home_from_properties(&default_gradle_home.join("gradle.properties")))
+        .map(|raw_path| expand_user_home(user_home, raw_path.trim()))
+        .unwrap_or(default_gradle_home);
+
+    debug!("Resolved Gradle user home -> {}", resolved.to_string_lossy());
+    resolved
+}
+
+fn gradle_user_home_from_jvm_options(jvm_options: &str) -> Option<String> {
+    jvm_options
+        .split_whitespace()
+        .rev()
+        .find_map(|option| option.strip_prefix("-Dgradle.user.home="))
+        .map(|value| value.trim_matches(|quote| quote == '"' || quote == '\'').to_string())
+}
+
+fn gradle_user_home_from_properties(properties_file: &Path) -> Option<String> {
+    read_property(properties_file, &["gradle.user.home", "systemProp.gradle.user.home"])
+}
+
+// Reads the last definition of any of the given keys from a Java properties file
+pub fn read_property(properties_file: &Path, keys: &[&str]) -> Option<String> {
+    let contents = fs::read_to_string(properties_file).ok()?;
+
+    contents
+        .lines()
+        .rev()
+        .map(|line| line.trim())
+        .filter(|line| !line.starts_with('#') && !line.starts_with('!'))
+        .filter_map(|line| line.split_once(['=', ':']))
+        .find(|(key, _)| keys.contains(&key.trim()))
+        .map(|(_, value)| value.trim().replace("\\:", ":").replace("\\=", "="))
+}
+
+fn expand_user_home(user_home: &Path, raw_path: &str) -> PathBuf {
+    match raw_path.strip_prefix("~/") {
+        Some(relative) => user_home.join(relative),
+        None if raw_path == "~" => user_home.to_path_buf(),
+        None => PathBuf::from(raw_path),
+    }
 }
 
 pub fn find_maven_local_repository(user_home: &Path) -> PathBuf {
@@ -21,51 +80,81 @@ pub fn find_konan_caches(user_home: &Path) -> PathBuf {
     user_home.to_path_buf().join(".konan")
 }
 
+// Used when no project roots are explicitly configured
+const STANDARD_PROJECT_LOCATIONS: [&str; 4] = ["AndroidStudioProjects", "IdeaProjects", "Projects", "Dev"];
+
 #[cached]
-pub fn find_all_gradle_projects(user_home: PathBuf) -> Vec<PathBuf> {
-    WalkDir::new(user_home)
+pub fn find_all_gradle_projects(user_home: PathBuf, discovery: ProjectDiscovery) -> Vec<PathBuf> {
+    // Without configured roots, the whole user home is scanned for standard project locations
+    let roots = if discovery.roots.is_empty() {
+        vec![user_home.clone()]
+    } else {
+        discovery
+            .roots
+            .iter()
+            .map(|root| expand_user_home(&user_home, &root.to_string_lossy()))
+            .collect::<Vec<_>>()
+    };
+
+    roots
         .into_iter()
-        .filter_map(|entry| entry.ok())
-        .filter(standard_project_locations)
-        .filter(ensure_gradle_project)
-        .map(|entry| entry.into_path())
+        .filter(|root| root.is_dir())
+        .flat_map(|root| {
+            let walker = match discovery.max_depth {
+                Some(depth) => WalkDir::new(root).max_depth(depth),
+                None => WalkDir::new(root),
+            };
+
+            walker
+                .into_iter()
+                .filter_entry(|entry| !matches_any(&discovery.exclude, entry.path()))
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_dir())
+                .filter(|entry| !discovery.roots.is_empty() || standard_project_locations(&user_home, entry))
+                .filter(|entry| discovery.include.is_empty() || matches_any(&discovery.include, entry.path()))
+                .filter(ensure_gradle_project)
+                .map(|entry| entry.into_path())
+                .collect::<Vec<_>>()
+        })
         .sorted()
+        .dedup()
         .collect::<Vec<_>>()
 }
 
-pub fn find_associated_filepaths(user_home: &Path, cached: DiskCached) -> Vec<PathBuf> {
+pub fn find_associated_filepaths(
+    user_home: &Path,
+    gradle_home: &Path,
+    gradle_projects: &[PathBuf],
+    cached: DiskCached,
+) -> Vec<PathBuf> {
     match cached {
-        DiskCached::Standalone(project_level) => {
-            let gradle_projects = find_all_gradle_projects(user_home.to_path_buf());
-
-            if gradle_projects.is_empty() {
-                return gradle_projects;
-            };
-
-            match project_level {
-                ProjectLevelDiskCache::BuildOutput => all_build_output_folders(&gradle_projects),
-                ProjectLevelDiskCache::GradleMetadata => all_metadata_files(&gradle_projects, ".gradle"),
-                ProjectLevelDiskCache::IdeaMetadata => all_metadata_files(&gradle_projects, ".idea"),
-            }
-        },
-        DiskCached::Shared(user_level) => match user_level.path_relative_to_user_home() {
-            None => vec![],
-            Some(path) => {
-                let home_dir = user_home_locator();
-                vec![home_dir.join(path)]
-            },
+        DiskCached::Standalone(project_level) => match project_level {
+            ProjectLevelDiskCache::BuildOutput => all_build_output_folders(gradle_projects),
+            ProjectLevelDiskCache::GradleMetadata => all_metadata_files(gradle_projects, ".gradle"),
+            ProjectLevelDiskCache::IdeaMetadata => all_metadata_files(gradle_projects, ".idea"),
         },
+        DiskCached::Shared(user_level) => user_level
+            .path_relative_to_gradle_home()
+            .map(|path| gradle_home.join(path))
+            .or_else(|| user_level.path_relative_to_user_home().map(|path| user_home.join(path)))
+            .into_iter()
+            .collect(),
     }
 }
 
-fn standard_project_locations(entry: &DirEntry) -> bool {
-    let entry_path = entry.path();
-    let entry_path_raw = entry_path.to_str().expect("Not a valid path");
+// Folders are matched by their exact name, since a path merely containing "Dev" could be anything
+fn standard_project_locations(user_home: &Path, entry: &DirEntry) -> bool {
+    let relative_path = entry.path().strip_prefix(user_home).unwrap_or(entry.path());
 
-    entry_path_raw.contains("AndroidStudioProjects")
-        || entry_path_raw.contains("IdeaProjects")
-        || entry_path_raw.contains("Projects")
-        || entry_path_raw.contains("Dev")
+    relative_path.components().any(|component| {
+        STANDARD_PROJECT_LOCATIONS
+            .iter()
+            .any(|location| component.as_os_str() == *location)
+    })
+}
+
+fn matches_any(patterns: &[Pattern], path: &Path) -> bool {
+    patterns.iter().any(|pattern| pattern.matches_path(path))
 }
 
 fn all_metadata_files(projects: &[PathBuf], target: &str) -> Vec<PathBuf> {
@@ -119,15 +208,28 @@ fn ensure_gradle_project(entry: &DirEntry) -> bool {
 #[cfg(test)]
 mod tests {
     use crate::core::disk::find_all_gradle_projects;
+    use crate::core::disk::locations::resolve_gradle_home;
+    use crate::core::models::ProjectDiscovery;
+    use glob::Pattern;
     use itertools::Itertools;
     use std::fs;
+    use std::path::{Path, PathBuf};
     use temp_dir::TempDir;
 
+    fn prepare_fake_gradle_project(root: &Path, relative_path: &str) {
+        let project_dir = root.join(relative_path);
+        fs::create_dir_all(&project_dir).expect("Cant create temporary fixture folder");
+
+        for file in ["settings.gradle", "build.gradle", "gradlew", "gradle.properties"] {
+            fs::write(project_dir.join(file), "foo").expect("Cant create fixture file");
+        }
+    }
+
     #[test]
     fn should_locate_no_projects() {
         let temp_dir = TempDir::new().expect("Cant create temp dir");
         let fake_user_home = temp_dir.path().to_path_buf();
-        let projects = find_all_gradle_projects(fake_user_home);
+        let projects = find_all_gradle_projects(fake_user_home, ProjectDiscovery::default());
 
         assert!(projects.is_empty());
     }
@@ -163,7 +265,7 @@ mod tests {
         }
 
         let fake_user_home = temp_dir.path();
-        let projects = find_all_gradle_projects(fake_user_home.to_path_buf());
+        let projects = find_all_gradle_projects(fake_user_home.to_path_buf(), ProjectDiscovery::default());
 
         let expected = ["AndroidStudioProjects/android-app", "IdeaProjects/jvm-app"]
             .into_iter()
@@ -173,4 +275,123 @@ mod tests {
 
         assert_eq!(projects, expected);
     }
+
+    #[test]
+    fn should_locate_gradle_projects_anywhere_under_standard_locations() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let fake_user_home = temp_dir.path();
+
+        prepare_fake_gradle_project(fake_user_home, "code/Projects/android-app");
+        prepare_fake_gradle_project(fake_user_home, "Dev/clients/jvm-app");
+        prepare_fake_gradle_project(fake_user_home, "code/MyProjects/ignored-app");
+        prepare_fake_gradle_project(fake_user_home, "workspace/DevTools/ignored-app");
+        prepare_fake_gradle_project(fake_user_home, "code/ignored-app");
+
+        let projects = find_all_gradle_projects(fake_user_home.to_path_buf(), ProjectDiscovery::default());
+
+        let expected = ["Dev/clients/jvm-app", "code/Projects/android-app"]
+            .into_iter()
+            .map(|item| fake_user_home.join(item))
+            .collect::<Vec<_>>();
+
+        assert_eq!(projects, expected);
+    }
+
+    #[test]
+    fn should_locate_gradle_projects_from_configured_roots() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let fake_user_home = temp_dir.path();
+
+        prepare_fake_gradle_project(fake_user_home, "work/android-app");
+        prepare_fake_gradle_project(fake_user_home, "work/archived/legacy-app");
+        prepare_fake_gradle_project(fake_user_home, "work/clients/acme/deeply/nested/app");
+        prepare_fake_gradle_project(fake_user_home, "src/jvm-lib");
+        prepare_fake_gradle_project(fake_user_home, "DevTools/ignored-app");
+
+        let discovery = ProjectDiscovery {
+            roots: vec![PathBuf::from("~/work"), fake_user_home.join("src")],
+            include: vec![],
+            exclude: vec![Pattern::new("**/archived").expect("Invalid pattern")],
+            max_depth: Some(3),
+        };
+
+        let projects = find_all_gradle_projects(fake_user_home.to_path_buf(), discovery);
+
+        let expected = ["src/jvm-lib", "work/android-app"]
+            .into_iter()
+            .map(|item| fake_user_home.join(item))
+            .collect::<Vec<_>>();
+
+        assert_eq!(projects, expected);
+    }
+
+    #[test]
+    fn should_locate_only_included_gradle_projects() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let fake_user_home = temp_dir.path();
+
+        prepare_fake_gradle_project(fake_user_home, "Projects/android-app");
+        prepare_fake_gradle_project(fake_user_home, "Projects/jvm-app");
+
+        let discovery = ProjectDiscovery {
+            include: vec![Pattern::new("**/android-*").expect("Invalid pattern")],
+            ..ProjectDiscovery::default()
+        };
+
+        let projects = find_all_gradle_projects(fake_user_home.to_path_buf(), discovery);
+
+        assert_eq!(projects, vec![fake_user_home.join("Projects/android-app")]);
+    }
+
+    #[test]
+    fn should_resolve_default_gradle_home() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let fake_user_home = temp_dir.path();
+
+        let gradle_home = resolve_gradle_home(fake_user_home, None, None, None);
+
+        assert_eq!(gradle_home, fake_user_home.join(".gradle"));
+    }
+
+    #[test]
+    fn should_resolve_gradle_home_from_gradle_properties() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let fake_user_home = temp_dir.path();
+
+        fs::create_dir(fake_user_home.join(".gradle")).expect("Cant create temporary fixture folder");
+        let properties = "org.gradle.caching=true\nsystemProp.gradle.user.home=/mnt/gradle\n";
+        fs::write(fake_user_home.join(".gradle/gradle.properties"), properties).expect("Cant create fixture file");
+
+        let gradle_home = resolve_gradle_home(fake_user_home, None, None, None);
+
+        assert_eq!(gradle_home, PathBuf::from("/mnt/gradle"));
+    }
+
+    #[test]
+    fn should_resolve_gradle_home_honoring_precedence() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let fake_user_home = temp_dir.path();
+        let gradle_opts = String::from("-Xmx2g -Dgradle.user.home=/opt/gradle");
+        let gradle_user_home_env = String::from("~/gradle-env");
+
+        let from_env = resolve_gradle_home(fake_user_home, None, None, Some(gradle_user_home_env.clone()));
+        assert_eq!(from_env, fake_user_home.join("gradle-env"));
+
+        let from_opts = resolve_gradle_home(
+            fake_user_home,
+            None,
+            Some(gradle_opts.clone()),
+            Some(gradle_user_home_env.clone()),
+        );
+        assert_eq!(from_opts, PathBuf::from("/opt/gradle"));
+
+        let cli_override = PathBuf::from("/data/gradle");
+        let from_cli = resolve_gradle_home(
+            fake_user_home,
+            Some(cli_override.as_path()),
+            Some(gradle_opts),
+            Some(gradle_user_home_env),
+        );
+        assert_eq!(from_cli, cli_override);
+    }
 }
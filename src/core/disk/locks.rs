@@ -0,0 +1,125 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+// Gradle cross-process lock files start with a state region (a protocol byte, then either a sequence number
+// or a dirty flag depending on the cache), followed by an information region written by LockInfoSerializer :
+// a protocol byte, the port used to ask the owner to release the lock, a lock id, then the owner PID and the
+// operation holding the lock, both as strings.
+// https://github.com/gradle/gradle/blob/master/platforms/core-execution/persistent-cache/src/main/java/org/gradle/cache/internal/filelock/LockInfoSerializer.java
+
+use crate::core::decoding::Decoder;
+use anyhow::ensure;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+const LOCK_FILE_EXTENSION: &str = "lock";
+
+// Lock files live next to the caches they protect, like caches/8.7/fileHashes/fileHashes.lock
+const LOCK_FILES_MAX_DEPTH: usize = 3;
+
+// Sizes of the state region, with a sequence number (DefaultLockStateSerializer) or a dirty flag
+// (Version1LockStateSerializer)
+const STATE_REGION_SIZES: [usize; 2] = [9, 2];
+
+const LOCK_INFO_PROTOCOL: u8 = 3;
+
+pub fn find_lock_owners(gradle_home: &Path) -> Vec<(PathBuf, u32)> {
+    WalkDir::new(gradle_home.join("caches"))
+        .max_depth(LOCK_FILES_MAX_DEPTH)
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.into_path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == LOCK_FILE_EXTENSION))
+        .filter_map(|path| {
+            let owner = fs::read(&path).ok().and_then(|bytes| read_lock_owner(&bytes))?;
+            Some((path, owner))
+        })
+        .collect::<Vec<_>>()
+}
+
+fn read_lock_owner(bytes: &[u8]) -> Option<u32> {
+    STATE_REGION_SIZES
+        .iter()
+        .find_map(|state_region_size| decode_lock_owner(bytes, *state_region_size).ok())
+}
+
+fn decode_lock_owner(bytes: &[u8], state_region_size: usize) -> anyhow::Result<u32> {
+    let mut decoder = Decoder::new(bytes);
+    decoder.skip(state_region_size)?;
+
+    let protocol = decoder.read_byte()?;
+    ensure!(
+        protocol == LOCK_INFO_PROTOCOL,
+        "unexpected lock info protocol {protocol}"
+    );
+
+    decoder.read_int()?;
+    decoder.read_long()?;
+    let owner = decoder.read_string()?.parse::<u32>()?;
+    decoder.read_string()?;
+
+    Ok(owner)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::disk::locks::find_lock_owners;
+    use itertools::Itertools;
+    use std::fs;
+    use std::path::Path;
+    use temp_dir::TempDir;
+
+    fn write_string(buffer: &mut Vec<u8>, value: &str) {
+        buffer.extend((value.len() as u16).to_be_bytes());
+        buffer.extend(value.as_bytes());
+    }
+
+    fn write_lock_file(path: &Path, owner: &str, sequence_number: bool) {
+        fs::create_dir_all(path.parent().expect("Lock files have parents")).expect("Cannot create lock dir");
+
+        let mut buffer = vec![1];
+        match sequence_number {
+            true => buffer.extend(7u64.to_be_bytes()),
+            false => buffer.push(0),
+        }
+
+        buffer.push(3);
+        buffer.extend(49152u32.to_be_bytes());
+        buffer.extend(4242424242u64.to_be_bytes());
+        write_string(&mut buffer, owner);
+        write_string(&mut buffer, "Gradle build daemon");
+
+        fs::write(path, buffer).expect("Cannot write lock file");
+    }
+
+    #[test]
+    fn should_find_owners_of_gradle_lock_files() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let caches = temp_dir.path().join("caches");
+
+        let dependencies_lock = caches.join("modules-2").join("modules-2.lock");
+        let file_hashes_lock = caches.join("8.7").join("fileHashes").join("fileHashes.lock");
+        let journal_lock = caches.join("journal-1").join("journal-1.lock");
+        let released_lock = caches.join("transforms-4").join("transforms-4.lock");
+        write_lock_file(&dependencies_lock, "45927", true);
+        write_lock_file(&file_hashes_lock, "12345", true);
+        write_lock_file(&journal_lock, "49739", false);
+        fs::create_dir_all(released_lock.parent().expect("Lock files have parents")).expect("Cannot create lock dir");
+        fs::write(&released_lock, [1, 0, 0, 0, 0, 0, 0, 0, 7]).expect("Cannot write lock file");
+        fs::write(caches.join("8.7").join("gc.properties"), "").expect("Cannot write file");
+
+        let owners = find_lock_owners(temp_dir.path())
+            .into_iter()
+            .sorted()
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            (file_hashes_lock, 12345),
+            (journal_lock, 49739),
+            (dependencies_lock, 45927),
+        ];
+
+        assert_eq!(owners, expected);
+    }
+}
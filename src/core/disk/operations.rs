@@ -1,11 +1,15 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use log::{debug, info};
+use crate::core::disk::resources::disk_usage;
+use crate::core::models::{PathRemoval, RemovalStatus};
+use log::debug;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use ubyte::ByteUnit;
 
-pub fn cleanup_resources(paths_to_remove: &[PathBuf]) {
+pub fn cleanup_resources(paths_to_remove: &[PathBuf]) -> Vec<PathRemoval> {
     debug!("");
     debug!("Removing the following :");
     debug!("");
@@ -16,24 +20,61 @@ pub fn cleanup_resources(paths_to_remove: &[PathBuf]) {
 
     debug!("");
 
-    let errors = paths_to_remove
+    paths_to_remove
         .iter()
-        .map(fs::remove_dir_all)
-        .filter(|deletion| deletion.is_err())
-        .map(|deletion| deletion.expect_err("Expecting an error").to_string())
-        .collect::<Vec<String>>();
+        .map(|path| PathRemoval::new(path.clone(), remove_path(path)))
+        .collect::<Vec<_>>()
+}
+
+fn remove_path(path: &Path) -> RemovalStatus {
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(error) if error.kind() == ErrorKind::NotFound => return RemovalStatus::NotPresent,
+        Err(error) => return failed_removal(path, &error, ByteUnit::from(0)),
+    };
+
+    let size_before = disk_usage(path).unwrap_or(ByteUnit::from(0));
+
+    let removal = if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    };
 
-    if !errors.is_empty() {
-        info!("Some of the target repositories were not removed (not found)")
+    match removal {
+        Ok(()) => RemovalStatus::Removed,
+        Err(error) => failed_removal(path, &error, size_before),
+    }
+}
+
+// Removals may stop halfway through a folder, leaving only some of its contents behind
+pub fn failed_removal(path: &Path, error: &Error, size_before: ByteUnit) -> RemovalStatus {
+    if error.kind() == ErrorKind::NotFound || !path.exists() {
+        return RemovalStatus::NotPresent;
+    }
+
+    let remaining = disk_usage(path).unwrap_or(size_before);
+
+    if remaining < size_before {
+        return RemovalStatus::PartiallyRemoved(remaining);
+    }
+
+    match error.kind() {
+        ErrorKind::PermissionDenied => RemovalStatus::PermissionDenied,
+        _ => RemovalStatus::Failed(error.to_string()),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::core::disk::cleanup_resources;
+    use crate::core::disk::operations::failed_removal;
+    use crate::core::models::{PathRemoval, RemovalStatus};
     use std::fs;
+    use std::io::{Error, ErrorKind};
     use std::path::PathBuf;
     use temp_dir::TempDir;
+    use ubyte::ByteUnit;
 
     #[test]
     fn should_remove_target_paths_ignoring_errors() {
@@ -56,8 +97,52 @@ mod tests {
 
         let paths = to_remove.iter().map(PathBuf::from).collect::<Vec<_>>();
 
-        cleanup_resources(&paths);
+        let removals = cleanup_resources(&paths);
 
+        assert!(removals.iter().all(|removal| !removal.status.is_failure()));
         paths.into_iter().for_each(|path| assert!(!path.exists()))
     }
+
+    #[test]
+    fn should_report_status_for_each_target_path() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let build_output = temp_dir.path().join("build");
+        let report = temp_dir.path().join("report.hprof");
+        let missing = temp_dir.path().join(".idea");
+
+        fs::create_dir_all(build_output.join("classes")).expect("Cant create temporary fixture folder");
+        fs::write(&report, "heap").expect("Cant create temporary fixture file");
+
+        let paths = vec![build_output.clone(), report.clone(), missing.clone()];
+
+        let removals = cleanup_resources(&paths);
+
+        let expected = vec![
+            PathRemoval::new(build_output, RemovalStatus::Removed),
+            PathRemoval::new(report, RemovalStatus::Removed),
+            PathRemoval::new(missing, RemovalStatus::NotPresent),
+        ];
+
+        assert_eq!(removals, expected);
+    }
+
+    #[test]
+    fn should_report_failures_for_paths_left_behind() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let build_output = temp_dir.path().join("build");
+
+        fs::create_dir_all(&build_output).expect("Cant create temporary fixture folder");
+        fs::write(build_output.join("output.jar"), "jar").expect("Cant create temporary fixture file");
+
+        let error = Error::new(ErrorKind::PermissionDenied, "Permission denied");
+        let status = failed_removal(&build_output, &error, ByteUnit::from(3));
+        assert_eq!(status, RemovalStatus::PermissionDenied);
+
+        let status = failed_removal(&build_output, &error, ByteUnit::from(10));
+        assert_eq!(status, RemovalStatus::PartiallyRemoved(ByteUnit::from(3)));
+
+        let missing = temp_dir.path().join(".idea");
+        let status = failed_removal(&missing, &error, ByteUnit::from(10));
+        assert_eq!(status, RemovalStatus::NotPresent);
+    }
 }
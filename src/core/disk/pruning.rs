@@ -0,0 +1,402 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::disk::locations::read_property;
+use crate::core::models::{InstalledToolchain, ToolchainRequest};
+use anyhow::Context;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
+
+// Folders inside a project that never hold build logic
+const IGNORED_PROJECT_FOLDERS: [&str; 5] = ["build", ".gradle", ".idea", ".git", "node_modules"];
+
+// https://docs.gradle.org/current/userguide/gradle_wrapper.html
+pub fn find_referenced_distributions(gradle_projects: &[PathBuf]) -> HashSet<String> {
+    gradle_projects
+        .iter()
+        .map(|project| project.join("gradle").join("wrapper").join("gradle-wrapper.properties"))
+        .filter_map(|properties_file| read_property(&properties_file, &["distributionUrl"]))
+        .filter_map(|url| distribution_name_from_url(&url))
+        .collect()
+}
+
+pub fn find_installed_distributions(gradle_home: &Path) -> Vec<PathBuf> {
+    WalkDir::new(gradle_home.join("wrapper").join("dists"))
+        .min_depth(1)
+        .max_depth(1)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_dir())
+        .map(|entry| entry.into_path())
+        .sorted()
+        .collect()
+}
+
+// Distributions are unpacked at wrapper/dists/<archive name without .zip>/<hash of the url>
+fn distribution_name_from_url(url: &str) -> Option<String> {
+    url.rsplit('/')
+        .next()
+        .and_then(|archive| archive.strip_suffix(".zip"))
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_string())
+}
+
+// https://docs.gradle.org/current/userguide/toolchains.html
+// https://docs.gradle.org/current/userguide/gradle_daemon.html#sec:daemon_jvm_criteria
+pub fn find_requested_toolchains(gradle_projects: &[PathBuf]) -> anyhow::Result<HashSet<ToolchainRequest>> {
+    gradle_projects
+        .iter()
+        .map(|project| {
+            let mut requests = find_build_scripts(project)
+                .into_iter()
+                .filter_map(|script| fs::read_to_string(&script).ok().map(|contents| (script, contents)))
+                .map(|(script, contents)| {
+                    toolchains_from_build_script(&contents).with_context(|| {
+                        format!("Cannot tell which JDK toolchains {} requests", script.to_string_lossy())
+                    })
+                })
+                .flatten_ok()
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            requests.extend(toolchain_from_daemon_criteria(project)?);
+            Ok(requests)
+        })
+        .flatten_ok()
+        .collect()
+}
+
+pub fn find_installed_toolchains(gradle_home: &Path) -> Vec<InstalledToolchain> {
+    WalkDir::new(gradle_home.join("jdks"))
+        .min_depth(1)
+        .max_depth(1)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_dir())
+        .map(|entry| entry.into_path())
+        .sorted()
+        .filter_map(|path| describe_installed_toolchain(&path))
+        .collect()
+}
+
+fn find_build_scripts(project: &Path) -> Vec<PathBuf> {
+    WalkDir::new(project)
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || !is_ignored_project_folder(entry))
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            name.ends_with(".gradle") || name.ends_with(".gradle.kts")
+        })
+        .collect()
+}
+
+fn is_ignored_project_folder(entry: &DirEntry) -> bool {
+    let name = entry.file_name().to_string_lossy();
+    entry.file_type().is_dir() && IGNORED_PROJECT_FOLDERS.contains(&name.as_ref())
+}
+
+// Matches jvmToolchain(17), jvmToolchain { languageVersion = JavaLanguageVersion.of(17) } and friends,
+// returning nothing when versions come from elsewhere, like JavaLanguageVersion.of(libs.versions.jdk.get())
+fn toolchains_from_build_script(contents: &str) -> Option<Vec<ToolchainRequest>> {
+    let major_versions = ["jvmToolchain(", "JavaLanguageVersion.of("]
+        .iter()
+        .flat_map(|marker| contents.match_indices(marker))
+        .map(|(index, marker)| leading_number(&contents[index + marker.len()..]))
+        .collect::<Option<Vec<_>>>()?;
+
+    let vendors = contents
+        .match_indices("JvmVendorSpec.")
+        .map(|(index, marker)| leading_identifier(&contents[index + marker.len()..]))
+        .collect::<Vec<_>>();
+
+    // Vendors can only be tied to versions when a single toolchain is requested, otherwise any vendor is kept
+    let vendor = match (major_versions.as_slice(), vendors.as_slice()) {
+        ([_], [vendor]) if !vendor.is_empty() && vendor != "matching" => Some(vendor.clone()),
+        _ => None,
+    };
+
+    let requests = major_versions
+        .into_iter()
+        .map(|major_version| ToolchainRequest::new(major_version, vendor.clone()))
+        .collect();
+
+    Some(requests)
+}
+
+fn toolchain_from_daemon_criteria(project: &Path) -> anyhow::Result<Option<ToolchainRequest>> {
+    let properties_file = project.join("gradle").join("gradle-daemon-jvm.properties");
+    let Some(raw_version) = read_property(&properties_file, &["toolchainVersion"]) else {
+        return Ok(None);
+    };
+
+    let major_version = leading_number(&raw_version).with_context(|| {
+        format!(
+            "Cannot tell which JDK toolchain {} requests",
+            properties_file.to_string_lossy()
+        )
+    })?;
+
+    let vendor = read_property(&properties_file, &["toolchainVendor"]).filter(|vendor| !vendor.is_empty());
+    Ok(Some(ToolchainRequest::new(major_version, vendor)))
+}
+
+fn describe_installed_toolchain(path: &Path) -> Option<InstalledToolchain> {
+    let release_file = WalkDir::new(path)
+        .max_depth(3)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .find(|entry| entry.file_type().is_file() && entry.file_name() == "release")
+        .map(|entry| entry.into_path());
+
+    let from_release = release_file.as_deref().and_then(|release| {
+        let java_version = read_property(release, &["JAVA_VERSION"])?;
+        let vendor = read_property(release, &["IMPLEMENTOR"]).map(|raw| raw.trim_matches('"').to_string());
+        Some((major_from_java_version(java_version.trim_matches('"'))?, vendor))
+    });
+
+    // Gradle names provisioned JDKs as <vendor>-<major version>-<architecture>-<os>
+    let folder_name = path.file_name()?.to_string_lossy().to_string();
+    let from_folder_name = folder_name
+        .split('-')
+        .find_map(|token| token.parse::<u32>().ok())
+        .map(|major_version| {
+            let vendor = folder_name
+                .split('-')
+                .next()
+                .filter(|token| token.parse::<u32>().is_err());
+            (major_version, vendor.map(|name| name.to_string()))
+        });
+
+    from_release
+        .or(from_folder_name)
+        .map(|(major_version, vendor)| InstalledToolchain::new(path.to_path_buf(), major_version, vendor))
+}
+
+fn major_from_java_version(java_version: &str) -> Option<u32> {
+    let mut segments = java_version.split(['.', '_', '+', '-']);
+    match segments.next()? {
+        "1" => leading_number(segments.next()?),
+        major => leading_number(major),
+    }
+}
+
+fn leading_number(raw: &str) -> Option<u32> {
+    let digits = raw
+        .trim_start_matches(|character: char| character.is_whitespace() || character == '"')
+        .chars()
+        .take_while(|character| character.is_ascii_digit())
+        .collect::<String>();
+
+    digits.parse().ok()
+}
+
+fn leading_identifier(raw: &str) -> String {
+    raw.chars()
+        .take_while(|character| character.is_ascii_alphanumeric() || *character == '_')
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::disk::pruning::{
+        find_installed_distributions, find_installed_toolchains, find_referenced_distributions,
+        find_requested_toolchains,
+    };
+    use crate::core::models::{InstalledToolchain, ToolchainRequest};
+    use std::collections::HashSet;
+    use std::fs;
+    use std::path::PathBuf;
+    use temp_dir::TempDir;
+
+    #[test]
+    fn should_find_distributions_referenced_by_projects() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        let wrappers = [
+            (
+                "my-project",
+                "distributionUrl=https\\://services.gradle.org/distributions/gradle-8.7-bin.zip",
+            ),
+            (
+                "other-project",
+                "distributionBase=GRADLE_USER_HOME\ndistributionUrl=https\\://mirror.acme.com/gradle-8.10-all.zip",
+            ),
+            ("broken-project", "distributionBase=GRADLE_USER_HOME"),
+        ];
+
+        for (project, contents) in wrappers {
+            let wrapper_folder = temp_dir.path().join(project).join("gradle/wrapper");
+            fs::create_dir_all(&wrapper_folder).expect("Cant create fixture folder");
+            fs::write(wrapper_folder.join("gradle-wrapper.properties"), contents).expect("Cant create fixture file");
+        }
+
+        let projects = ["my-project", "other-project", "broken-project", "missing-project"]
+            .iter()
+            .map(|project| temp_dir.path().join(project))
+            .collect::<Vec<_>>();
+
+        let referenced = find_referenced_distributions(&projects);
+
+        let expected = HashSet::from([String::from("gradle-8.7-bin"), String::from("gradle-8.10-all")]);
+        assert_eq!(referenced, expected);
+    }
+
+    #[test]
+    fn should_find_installed_distributions() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        for distribution in ["gradle-8.7-bin/abcd", "gradle-8.2-all/ef01"] {
+            let folder = temp_dir.path().join("wrapper/dists").join(distribution);
+            fs::create_dir_all(folder).expect("Cant create fixture folder");
+        }
+
+        let installed = find_installed_distributions(temp_dir.path());
+
+        let expected = vec![
+            temp_dir.path().join("wrapper/dists/gradle-8.2-all"),
+            temp_dir.path().join("wrapper/dists/gradle-8.7-bin"),
+        ];
+
+        assert_eq!(installed, expected);
+    }
+
+    #[test]
+    fn should_find_toolchains_requested_by_projects() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        let files = [
+            ("kotlin-project/build.gradle.kts", "kotlin {\n    jvmToolchain(17)\n}"),
+            (
+                "java-project/app/build.gradle",
+                "java {\n    toolchain {\n        languageVersion = JavaLanguageVersion.of(21)\n        vendor = JvmVendorSpec.ADOPTIUM\n    }\n}",
+            ),
+            ("java-project/build/generated.gradle", "kotlin { jvmToolchain(8) }"),
+            (
+                "java-project/gradle/gradle-daemon-jvm.properties",
+                "toolchainVendor=AZUL\ntoolchainVersion=11",
+            ),
+        ];
+
+        for (file, contents) in files {
+            let path = temp_dir.path().join(file);
+            fs::create_dir_all(path.parent().expect("Expecting a parent folder")).expect("Cant create fixture folder");
+            fs::write(path, contents).expect("Cant create fixture file");
+        }
+
+        let projects = vec![
+            temp_dir.path().join("kotlin-project"),
+            temp_dir.path().join("java-project"),
+        ];
+
+        let requested = find_requested_toolchains(&projects).expect("Cannot find requested toolchains");
+
+        let expected = HashSet::from([
+            ToolchainRequest::new(17, None),
+            ToolchainRequest::new(21, Some(String::from("ADOPTIUM"))),
+            ToolchainRequest::new(11, Some(String::from("AZUL"))),
+        ]);
+
+        assert_eq!(requested, expected);
+    }
+
+    #[test]
+    fn should_keep_any_vendor_when_several_toolchains_are_requested() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let build_script = temp_dir.path().join("multi-jdk-project/build.gradle.kts");
+        let contents = "kotlin { jvmToolchain(17) }\n\
+            tasks.register<Test>(\"legacyTest\") {\n\
+                javaLauncher = javaToolchains.launcherFor {\n\
+                    languageVersion = JavaLanguageVersion.of(11)\n\
+                    vendor = JvmVendorSpec.AZUL\n\
+                }\n\
+            }";
+
+        fs::create_dir_all(build_script.parent().expect("Expecting a parent folder")).expect("Cant create folder");
+        fs::write(build_script, contents).expect("Cant create fixture file");
+
+        let requested = find_requested_toolchains(&[temp_dir.path().join("multi-jdk-project")])
+            .expect("Cannot find requested toolchains");
+
+        let expected = HashSet::from([ToolchainRequest::new(17, None), ToolchainRequest::new(11, None)]);
+        assert_eq!(requested, expected);
+    }
+
+    #[test]
+    fn should_not_guess_toolchains_requested_from_elsewhere() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        let files = [
+            (
+                "catalog-project/build.gradle.kts",
+                "java { toolchain { languageVersion = JavaLanguageVersion.of(libs.versions.jdk.get()) } }",
+            ),
+            ("variable-project/build.gradle", "kotlin { jvmToolchain(jdkVersion) }"),
+            (
+                "criteria-project/gradle/gradle-daemon-jvm.properties",
+                "toolchainVersion=${jdk}",
+            ),
+        ];
+
+        for (file, contents) in files {
+            let path = temp_dir.path().join(file);
+            fs::create_dir_all(path.parent().expect("Expecting a parent folder")).expect("Cant create fixture folder");
+            fs::write(path, contents).expect("Cant create fixture file");
+        }
+
+        for project in ["catalog-project", "variable-project", "criteria-project"] {
+            assert!(find_requested_toolchains(&[temp_dir.path().join(project)]).is_err());
+        }
+    }
+
+    #[test]
+    fn should_find_installed_toolchains() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        let release = temp_dir.path().join("jdks/jdk-downloaded/jdk-1.8.0_392/release");
+        fs::create_dir_all(release.parent().expect("Expecting a parent folder")).expect("Cant create fixture folder");
+        fs::write(release, "IMPLEMENTOR=\"Amazon.com Inc.\"\nJAVA_VERSION=\"1.8.0_392\"").expect("Cant create file");
+
+        for folder in ["jdks/eclipse_adoptium-17-amd64-linux.2", "jdks/unknown-jdk"] {
+            fs::create_dir_all(temp_dir.path().join(folder)).expect("Cant create fixture folder");
+        }
+
+        fs::write(temp_dir.path().join("jdks/eclipse_adoptium-17-amd64-linux.2.lock"), "").expect("Cant create file");
+
+        let installed = find_installed_toolchains(temp_dir.path());
+
+        let expected = vec![
+            InstalledToolchain::new(
+                temp_dir.path().join("jdks/eclipse_adoptium-17-amd64-linux.2"),
+                17,
+                Some(String::from("eclipse_adoptium")),
+            ),
+            InstalledToolchain::new(
+                temp_dir.path().join("jdks/jdk-downloaded"),
+                8,
+                Some(String::from("Amazon.com Inc.")),
+            ),
+        ];
+
+        assert_eq!(installed, expected);
+    }
+
+    #[test]
+    fn should_match_toolchains_by_version_and_vendor() {
+        let installed = InstalledToolchain::new(
+            PathBuf::from("eclipse_adoptium-17-amd64-linux"),
+            17,
+            Some(String::from("eclipse_adoptium")),
+        );
+
+        assert!(ToolchainRequest::new(17, None).matches(&installed));
+        assert!(ToolchainRequest::new(17, Some(String::from("ADOPTIUM"))).matches(&installed));
+        assert!(!ToolchainRequest::new(17, Some(String::from("AZUL"))).matches(&installed));
+        assert!(!ToolchainRequest::new(21, None).matches(&installed));
+    }
+}
@@ -0,0 +1,455 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+// Quarantined paths are moved into <quarantine-dir>/<id>/<index>, next to a manifest recording where each one
+// came from, so that a wipe can be undone until the quarantine gets purged.
+
+use crate::core::disk::operations::failed_removal;
+use crate::core::disk::resources::disk_usage;
+use crate::core::models::{CacheEntry, DiskCached, PathRemoval, RemovalStatus};
+use anyhow::{Context, bail};
+use directories::BaseDirs;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use walkdir::WalkDir;
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct QuarantineManifest {
+    created_at: u64,
+    entries: Vec<QuarantinedEntry>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct QuarantinedEntry {
+    original: PathBuf,
+    stored: String,
+    category: String,
+    bytes: u64,
+}
+
+pub fn locate_quarantine_dir() -> PathBuf {
+    let base_dirs = BaseDirs::new().expect("Cannot retrieve standard system dirs");
+    base_dirs.data_local_dir().join("gradle-wiper").join("quarantine")
+}
+
+// Returns the id of the new quarantine (if anything was moved) and how each target path was moved away
+pub fn quarantine_resources(
+    quarantine_dir: &Path,
+    targets: &[(DiskCached, PathBuf)],
+) -> anyhow::Result<(Option<String>, Vec<PathRemoval>)> {
+    let (existing, missing): (Vec<_>, Vec<_>) = targets.iter().partition(|(_, path)| path.exists());
+    let mut removals = missing
+        .into_iter()
+        .map(|(_, path)| PathRemoval::new(path.clone(), RemovalStatus::NotPresent))
+        .collect::<Vec<_>>();
+
+    if existing.is_empty() {
+        return Ok((None, removals));
+    }
+
+    let (id, staging_dir) = create_staging_dir(quarantine_dir)?;
+    let created_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let mut manifest = QuarantineManifest {
+        created_at,
+        entries: Vec::new(),
+    };
+
+    // Entries are recorded before being moved, so that interrupted wipes can still be restored or purged
+    for (index, (cached, path)) in existing.into_iter().enumerate() {
+        let stored = index.to_string();
+        debug!("Quarantining {} as {}/{}", path.to_string_lossy(), id, stored);
+
+        let bytes = match disk_usage(path) {
+            Ok(bytes) => bytes,
+            Err(error) => {
+                removals.push(PathRemoval::new(path.clone(), RemovalStatus::Failed(error.to_string())));
+                continue;
+            },
+        };
+
+        manifest.entries.push(QuarantinedEntry {
+            original: path.clone(),
+            stored: stored.clone(),
+            category: cached.identifier().to_string(),
+            bytes: bytes.as_u64(),
+        });
+
+        if let Err(error) = write_manifest(&staging_dir, &manifest) {
+            manifest.entries.pop();
+            removals.push(PathRemoval::new(path.clone(), RemovalStatus::Failed(error.to_string())));
+            continue;
+        }
+
+        let status = match move_path(path, &staging_dir.join(&stored)) {
+            Ok(()) => RemovalStatus::Removed,
+            Err(error) => {
+                manifest.entries.pop();
+                failed_removal(path, &error, bytes)
+            },
+        };
+
+        removals.push(PathRemoval::new(path.clone(), status));
+    }
+
+    let quarantine = (!manifest.entries.is_empty()).then_some(id);
+    update_manifest(&staging_dir, created_at, manifest.entries)?;
+    Ok((quarantine, removals))
+}
+
+// Returns restored paths and the ones that could not be restored, which are kept in quarantine
+pub fn restore_quarantine(quarantine_dir: &Path, id: &str) -> anyhow::Result<(Vec<PathBuf>, Vec<String>)> {
+    // Ids name folders right under the quarantine dir, which restoring removes once emptied
+    if !matches!(
+        Path::new(id).components().collect::<Vec<_>>()[..],
+        [Component::Normal(_)]
+    ) {
+        bail!("Invalid quarantine id {id}");
+    }
+
+    let staging_dir = quarantine_dir.join(id);
+    let manifest = read_manifest(&staging_dir).with_context(|| format!("No quarantine found with id {id}"))?;
+
+    let mut restored = Vec::new();
+    let mut failures = Vec::new();
+    let mut remaining = Vec::new();
+
+    // Wipes interrupted halfway leave recorded entries that were never moved
+    let moved = manifest
+        .entries
+        .into_iter()
+        .filter(|entry| staging_dir.join(&entry.stored).exists());
+
+    for entry in moved {
+        let outcome = if entry.original.exists() {
+            Err(String::from("already exists"))
+        } else {
+            entry
+                .original
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| move_path(&staging_dir.join(&entry.stored), &entry.original))
+                .map_err(|error| error.to_string())
+        };
+
+        match outcome {
+            Ok(()) => restored.push(entry.original),
+            Err(reason) => {
+                failures.push(format!("{} : {}", entry.original.to_string_lossy(), reason));
+                remaining.push(entry);
+            },
+        }
+    }
+
+    update_manifest(&staging_dir, manifest.created_at, remaining)?;
+    Ok((restored, failures))
+}
+
+pub fn find_quarantined_entries(quarantine_dir: &Path) -> Vec<CacheEntry> {
+    let Ok(quarantines) = fs::read_dir(quarantine_dir) else {
+        return vec![];
+    };
+
+    quarantines
+        .flatten()
+        .map(|entry| entry.path())
+        .filter_map(|staging_dir| {
+            let manifest = read_manifest(&staging_dir).ok()?;
+            let created_at = UNIX_EPOCH + Duration::from_secs(manifest.created_at);
+
+            let entries = manifest
+                .entries
+                .into_iter()
+                .filter_map(|entry| {
+                    let cached = DiskCached::from_identifier(&entry.category)?;
+                    let path = staging_dir.join(entry.stored);
+                    Some(CacheEntry::new(path, cached, entry.bytes.into(), created_at))
+                })
+                .collect::<Vec<_>>();
+
+            Some(entries)
+        })
+        .flatten()
+        .collect::<Vec<_>>()
+}
+
+// Drops entries removed from quarantines, and quarantines left with no entries at all
+pub fn discard_purged_entries(quarantine_dir: &Path) -> anyhow::Result<()> {
+    let Ok(quarantines) = fs::read_dir(quarantine_dir) else {
+        return Ok(());
+    };
+
+    for staging_dir in quarantines.flatten().map(|entry| entry.path()) {
+        let Ok(manifest) = read_manifest(&staging_dir) else {
+            continue;
+        };
+
+        let remaining = manifest
+            .entries
+            .into_iter()
+            .filter(|entry| staging_dir.join(&entry.stored).exists())
+            .collect::<Vec<_>>();
+
+        update_manifest(&staging_dir, manifest.created_at, remaining)?;
+    }
+
+    Ok(())
+}
+
+fn create_staging_dir(quarantine_dir: &Path) -> anyhow::Result<(String, PathBuf)> {
+    fs::create_dir_all(quarantine_dir)?;
+
+    let timestamp = humantime::format_rfc3339_seconds(SystemTime::now())
+        .to_string()
+        .replace(['-', ':'], "");
+
+    for attempt in 1..=100 {
+        let id = match attempt {
+            1 => timestamp.clone(),
+            _ => format!("{timestamp}-{attempt}"),
+        };
+
+        let staging_dir = quarantine_dir.join(&id);
+        match fs::create_dir(&staging_dir) {
+            Ok(()) => return Ok((id, staging_dir)),
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    bail!("Cannot create a quarantine at {}", quarantine_dir.to_string_lossy())
+}
+
+fn read_manifest(staging_dir: &Path) -> anyhow::Result<QuarantineManifest> {
+    let contents = fs::read_to_string(staging_dir.join(MANIFEST_FILE))?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+fn write_manifest(staging_dir: &Path, manifest: &QuarantineManifest) -> anyhow::Result<()> {
+    fs::write(staging_dir.join(MANIFEST_FILE), serde_json::to_string_pretty(manifest)?)?;
+    Ok(())
+}
+
+fn update_manifest(staging_dir: &Path, created_at: u64, entries: Vec<QuarantinedEntry>) -> anyhow::Result<()> {
+    if entries.is_empty() {
+        fs::remove_dir_all(staging_dir)?;
+        return Ok(());
+    }
+
+    write_manifest(staging_dir, &QuarantineManifest { created_at, entries })
+}
+
+// Renaming is not possible across filesystems, like when caches live in a dedicated volume
+fn move_path(from: &Path, to: &Path) -> std::io::Result<()> {
+    match fs::rename(from, to) {
+        Err(error) if error.kind() == ErrorKind::CrossesDevices => {
+            if let Err(error) = copy_path(from, to) {
+                let _ = if to.is_dir() {
+                    fs::remove_dir_all(to)
+                } else {
+                    fs::remove_file(to)
+                };
+                return Err(error);
+            }
+
+            if from.is_dir() {
+                fs::remove_dir_all(from)
+            } else {
+                fs::remove_file(from)
+            }
+        },
+        outcome => outcome,
+    }
+}
+
+fn copy_path(from: &Path, to: &Path) -> std::io::Result<()> {
+    for entry in WalkDir::new(from) {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(from)
+            .expect("Walked entries live under their root");
+        let target = to.join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::disk::quarantine::{
+        QuarantineManifest, QuarantinedEntry, discard_purged_entries, find_quarantined_entries, quarantine_resources,
+        restore_quarantine, write_manifest,
+    };
+    use crate::core::models::{DiskCached, ProjectLevelDiskCache, UserLevelDiskCache};
+    use std::fs;
+    use std::path::Path;
+    use temp_dir::TempDir;
+
+    fn create_file(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().expect("Files have parents")).expect("Cannot create folder");
+        fs::write(path, contents).expect("Cannot write file");
+    }
+
+    #[test]
+    fn should_quarantine_and_restore_resources() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let quarantine_dir = temp_dir.path().join("quarantine");
+        let maven_local = temp_dir.path().join(".m2");
+        let idea_metadata = temp_dir.path().join("my-project").join(".idea");
+
+        create_file(&maven_local.join("repository").join("lib.jar"), "lib");
+        create_file(&idea_metadata.join("workspace.xml"), "<project/>");
+
+        let targets = vec![
+            (
+                DiskCached::Shared(UserLevelDiskCache::MavenLocalRepository),
+                maven_local.clone(),
+            ),
+            (
+                DiskCached::Standalone(ProjectLevelDiskCache::IdeaMetadata),
+                idea_metadata.clone(),
+            ),
+            (
+                DiskCached::Shared(UserLevelDiskCache::KonanCaches),
+                temp_dir.path().join(".konan"),
+            ),
+        ];
+
+        let (id, removals) = quarantine_resources(&quarantine_dir, &targets).expect("Cannot quarantine resources");
+        let id = id.expect("Resources should be quarantined");
+
+        assert!(removals.iter().all(|removal| !removal.status.is_failure()));
+        assert!(!maven_local.exists());
+        assert!(!idea_metadata.exists());
+        assert_eq!(find_quarantined_entries(&quarantine_dir).len(), 2);
+
+        let (restored, failures) = restore_quarantine(&quarantine_dir, &id).expect("Cannot restore quarantine");
+
+        assert!(failures.is_empty());
+        assert_eq!(restored, vec![maven_local.clone(), idea_metadata.clone()]);
+        assert!(maven_local.join("repository").join("lib.jar").exists());
+        assert!(idea_metadata.join("workspace.xml").exists());
+        assert!(!quarantine_dir.join(&id).exists());
+    }
+
+    #[test]
+    fn should_restore_quarantines_left_by_interrupted_wipes() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let quarantine_dir = temp_dir.path().join("quarantine");
+        let staging_dir = quarantine_dir.join("20260105T093012Z");
+        let maven_local = temp_dir.path().join(".m2");
+        let konan_caches = temp_dir.path().join(".konan");
+
+        create_file(&staging_dir.join("0").join("repository").join("lib.jar"), "lib");
+        create_file(&konan_caches.join("cache").join("klib"), "klib");
+
+        let entry = |original: &Path, stored: &str, category: &str| QuarantinedEntry {
+            original: original.to_path_buf(),
+            stored: stored.to_string(),
+            category: category.to_string(),
+            bytes: 3,
+        };
+
+        let manifest = QuarantineManifest {
+            created_at: 1767605412,
+            entries: vec![
+                entry(&maven_local, "0", "maven-local"),
+                entry(&konan_caches, "1", "konan-caches"),
+            ],
+        };
+        write_manifest(&staging_dir, &manifest).expect("Cannot write manifest");
+
+        let (restored, failures) =
+            restore_quarantine(&quarantine_dir, "20260105T093012Z").expect("Cannot restore quarantine");
+
+        assert!(failures.is_empty());
+        assert_eq!(restored, vec![maven_local.clone()]);
+        assert!(maven_local.join("repository").join("lib.jar").exists());
+        assert!(konan_caches.join("cache").join("klib").exists());
+        assert!(!staging_dir.exists());
+    }
+
+    #[test]
+    fn should_reject_quarantine_ids_escaping_the_quarantine_dir() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let quarantine_dir = temp_dir.path().join("quarantine");
+        let outsider = temp_dir.path().join("outsider");
+
+        create_file(&outsider.join("keep-me"), "precious");
+        write_manifest(
+            &outsider,
+            &QuarantineManifest {
+                created_at: 0,
+                entries: vec![],
+            },
+        )
+        .expect("Cannot write manifest");
+
+        for id in ["../outsider", "..", "/tmp", "20260105T093012Z/..", ""] {
+            assert!(
+                restore_quarantine(&quarantine_dir, id).is_err(),
+                "{id} should be rejected"
+            );
+        }
+
+        assert!(outsider.join("keep-me").exists());
+    }
+
+    #[test]
+    fn should_keep_in_quarantine_what_cannot_be_restored() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let quarantine_dir = temp_dir.path().join("quarantine");
+        let build_cache = temp_dir.path().join(".gradle").join("caches");
+
+        create_file(&build_cache.join("entry"), "cached");
+
+        let targets = vec![(
+            DiskCached::Shared(UserLevelDiskCache::GradleBuildCaching),
+            build_cache.clone(),
+        )];
+        let (id, _) = quarantine_resources(&quarantine_dir, &targets).expect("Cannot quarantine resources");
+        let id = id.expect("Resources should be quarantined");
+
+        create_file(&build_cache.join("newer-entry"), "cached");
+
+        let (restored, failures) = restore_quarantine(&quarantine_dir, &id).expect("Cannot restore quarantine");
+
+        assert!(restored.is_empty());
+        assert_eq!(failures.len(), 1);
+        assert_eq!(find_quarantined_entries(&quarantine_dir).len(), 1);
+    }
+
+    #[test]
+    fn should_discard_purged_quarantines() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        let quarantine_dir = temp_dir.path().join("quarantine");
+        let daemon_logs = temp_dir.path().join(".gradle").join("daemon");
+
+        create_file(&daemon_logs.join("daemon.log"), "logs");
+
+        let targets = vec![(DiskCached::Shared(UserLevelDiskCache::GradleDaemonLogs), daemon_logs)];
+        let (id, _) = quarantine_resources(&quarantine_dir, &targets).expect("Cannot quarantine resources");
+        let id = id.expect("Resources should be quarantined");
+
+        find_quarantined_entries(&quarantine_dir)
+            .iter()
+            .for_each(|entry| fs::remove_dir_all(&entry.path).expect("Cannot remove entry"));
+
+        discard_purged_entries(&quarantine_dir).expect("Cannot discard purged entries");
+
+        assert!(!quarantine_dir.join(id).exists());
+        assert!(restore_quarantine(&quarantine_dir, "unknown").is_err());
+    }
+}
@@ -1,9 +1,10 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::{AllocatedResource, ProjectLevelDiskCache, UseCase, UserLevelDiskCache};
+use crate::core::models::{AllocatedResource, ProjectLevelDiskCache, ProjectUsage, UseCase, UserLevelDiskCache};
 use itertools::Itertools;
 use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 use ubyte::ByteUnit;
 use walkdir::{DirEntry, WalkDir};
 
@@ -21,6 +22,30 @@ pub fn resources_used_by_gradle_projects(projects: &[PathBuf]) -> anyhow::Result
     Ok(AllocatedResource::new(use_case, total_amount))
 }
 
+pub fn resources_used_per_gradle_project_breakdown(projects: &[PathBuf]) -> anyhow::Result<Vec<ProjectUsage>> {
+    let usages = projects
+        .iter()
+        .map(|project| {
+            let build_output = resources_used_per_gradle_project(project.as_path())?.amount;
+            let gradle_metadata = total_disk_size(project.join(".gradle").as_path(), ensure_file)?;
+            let idea_metadata = total_disk_size(project.join(".idea").as_path(), ensure_file)?;
+            Ok(ProjectUsage::new(
+                project.clone(),
+                build_output,
+                gradle_metadata,
+                idea_metadata,
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let sorted = usages
+        .into_iter()
+        .sorted_by(|first, second| second.total().cmp(&first.total()))
+        .collect::<Vec<_>>();
+
+    Ok(sorted)
+}
+
 fn resources_used_per_gradle_project(gradle_project: &Path) -> anyhow::Result<AllocatedResource> {
     let use_case = UseCase::from(ProjectLevelDiskCache::BuildOutput);
     let disk_dize = total_disk_size(gradle_project, ensure_build_output_file)?;
@@ -48,7 +73,12 @@ pub fn resources_used_by_gradle_home(gradle_home: &Path) -> anyhow::Result<Vec<A
         .into_iter()
         .filter_map(|entry| entry.ok())
         .filter(ensure_file)
-        .map(|entry| (size_for_entry(&entry), evaluate_use_case_from_gradle_home(&entry)))
+        .map(|entry| {
+            (
+                size_for_entry(&entry),
+                evaluate_use_case_from_gradle_home(gradle_home, &entry),
+            )
+        })
         .filter(|item| item.1 != UseCase::from(UserLevelDiskCache::GradleOtherCaches))
         .chunk_by(|item| item.1)
         .into_iter()
@@ -60,6 +90,23 @@ pub fn resources_used_by_gradle_home(gradle_home: &Path) -> anyhow::Result<Vec<A
     Ok(total_per_use_case)
 }
 
+pub fn disk_usage(path: &Path) -> anyhow::Result<ByteUnit> {
+    total_disk_size(path, ensure_file)
+}
+
+pub fn disk_usage_and_last_use(path: &Path) -> anyhow::Result<(ByteUnit, SystemTime)> {
+    let (disk_size, last_used) = WalkDir::new(path)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(ensure_file)
+        .map(|entry| (size_for_entry(&entry), last_use_for_entry(&entry)))
+        .fold((0, UNIX_EPOCH), |(total, latest), (size, used)| {
+            (total + size, latest.max(used))
+        });
+
+    Ok((ByteUnit::from(disk_size), last_used))
+}
+
 fn total_disk_size(folder_path: &Path, filter: fn(&DirEntry) -> bool) -> anyhow::Result<ByteUnit> {
     let Ok(true) = folder_path.try_exists() else {
         return Ok(ByteUnit::from(0));
@@ -79,6 +126,13 @@ fn size_for_entry(entry: &DirEntry) -> u64 {
     entry.metadata().expect("Expecting a valid metadata for entry").len()
 }
 
+fn last_use_for_entry(entry: &DirEntry) -> SystemTime {
+    let metadata = entry.metadata().expect("Expecting a valid metadata for entry");
+    let modified = metadata.modified().unwrap_or(UNIX_EPOCH);
+    let accessed = metadata.accessed().unwrap_or(UNIX_EPOCH);
+    modified.max(accessed)
+}
+
 fn ensure_file(entry: &DirEntry) -> bool {
     entry
         .metadata()
@@ -98,25 +152,28 @@ fn ensure_build_output_file(entry: &DirEntry) -> bool {
     build_output && build_output_file
 }
 
-fn evaluate_use_case_from_gradle_home(entry: &DirEntry) -> UseCase {
-    let raw_path = entry
-        .path()
-        .to_str()
-        .expect("Should be able to evaluate path as string");
+fn evaluate_use_case_from_gradle_home(gradle_home: &Path, entry: &DirEntry) -> UseCase {
+    let relative_path = entry.path().strip_prefix(gradle_home).unwrap_or(entry.path());
 
     // https://docs.gradle.org/current/userguide/configuration_cache.html
     // https://docs.gradle.org/current/userguide/directory_layout.html
-    let cache_type = match raw_path {
-        _ if raw_path.contains(".gradle/caches") => UserLevelDiskCache::GradleBuildCaching,
-        _ if raw_path.contains(".gradle/configuration-cache") => UserLevelDiskCache::GradleConfigurationCaching,
-        _ if raw_path.contains(".gradle/daemon") => UserLevelDiskCache::GradleDaemonLogs,
-        _ if raw_path.contains(".gradle/jdks") => UserLevelDiskCache::GradleJDKToolchains,
-        _ if raw_path.contains(".gradle/wrapper") => UserLevelDiskCache::GradleDistributions,
-        _ if raw_path.contains(".gradle/.tmp") => UserLevelDiskCache::GradleTemporaryFiles,
-        _ if raw_path.contains(".gradle/native") => UserLevelDiskCache::GradleNativeFiles,
-        _ if raw_path.contains(".gradle/build-scan-data") => UserLevelDiskCache::GradleBuildScans,
-        _ => UserLevelDiskCache::GradleOtherCaches,
-    };
+    let cache_type = [
+        UserLevelDiskCache::GradleBuildCaching,
+        UserLevelDiskCache::GradleConfigurationCaching,
+        UserLevelDiskCache::GradleDaemonLogs,
+        UserLevelDiskCache::GradleJDKToolchains,
+        UserLevelDiskCache::GradleDistributions,
+        UserLevelDiskCache::GradleTemporaryFiles,
+        UserLevelDiskCache::GradleNativeFiles,
+        UserLevelDiskCache::GradleBuildScans,
+    ]
+    .into_iter()
+    .find(|cache| {
+        cache
+            .path_relative_to_gradle_home()
+            .is_some_and(|cache_path| relative_path.starts_with(cache_path))
+    })
+    .unwrap_or(UserLevelDiskCache::GradleOtherCaches);
 
     UseCase::from(cache_type)
 }
@@ -125,8 +182,9 @@ fn evaluate_use_case_from_gradle_home(entry: &DirEntry) -> UseCase {
 mod tests {
     use crate::core::disk::{
         resources_used_by_gradle_home, resources_used_by_gradle_projects, resources_used_by_maven_local_repository,
+        resources_used_per_gradle_project_breakdown,
     };
-    use crate::core::models::{AllocatedResource, ProjectLevelDiskCache, UseCase, UserLevelDiskCache};
+    use crate::core::models::{AllocatedResource, ProjectLevelDiskCache, ProjectUsage, UseCase, UserLevelDiskCache};
     use fake::{Fake, StringFaker};
     use std::fs;
     use std::fs::File;
@@ -217,8 +275,8 @@ mod tests {
 
         create_fake_1kb_file(&temp_dir, ".gradle/configuration-cache");
 
-        let fake_gradle_home_path = temp_dir.path();
-        let usages = resources_used_by_gradle_home(fake_gradle_home_path).expect("Cannot compute use cases");
+        let fake_gradle_home_path = temp_dir.path().join(".gradle");
+        let usages = resources_used_by_gradle_home(&fake_gradle_home_path).expect("Cannot compute use cases");
 
         let expected = vec![
             AllocatedResource::new(
@@ -294,4 +352,43 @@ mod tests {
         let expected = AllocatedResource::new(use_case, 5.kilobytes());
         assert_eq!(usage, expected)
     }
+
+    #[test]
+    fn should_break_down_resources_per_gradle_project() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+
+        prepare_fake_gradle_projects(&temp_dir);
+
+        for folder in [
+            "IdeaProjects",
+            "IdeaProjects/other-project",
+            "IdeaProjects/other-project/build",
+        ] {
+            fs::create_dir(temp_dir.path().join(folder)).expect("Cant create temporary fixture folder");
+        }
+
+        for _ in 0..2 {
+            create_fake_1kb_file(&temp_dir, "AndroidStudioProjects/my-project/build");
+        }
+
+        create_fake_1kb_file(&temp_dir, "AndroidStudioProjects/my-project/.gradle");
+        create_fake_1kb_file(&temp_dir, "AndroidStudioProjects/my-project/.idea");
+
+        for _ in 0..5 {
+            create_fake_1kb_file(&temp_dir, "IdeaProjects/other-project/build");
+        }
+
+        let my_project = temp_dir.path().join("AndroidStudioProjects/my-project");
+        let other_project = temp_dir.path().join("IdeaProjects/other-project");
+        let projects = vec![my_project.clone(), other_project.clone()];
+
+        let usages = resources_used_per_gradle_project_breakdown(&projects).expect("Cannot compute use cases");
+
+        let expected = vec![
+            ProjectUsage::new(other_project, 5.kilobytes(), ByteUnit::from(0), ByteUnit::from(0)),
+            ProjectUsage::new(my_project, 2.kilobytes(), 1.kilobytes(), 1.kilobytes()),
+        ];
+
+        assert_eq!(usages, expected)
+    }
 }
@@ -0,0 +1,294 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+use crate::core::disk::resources::disk_usage_and_last_use;
+use crate::core::models::{CacheEntry, DiskCached, UserLevelDiskCache};
+use itertools::Itertools;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use ubyte::ByteUnit;
+use walkdir::WalkDir;
+
+// Bookkeeping files Gradle keeps next to cache entries, which must survive partial cleanups
+const CACHE_BOOKKEEPING_FILES: [&str; 2] = ["gc.properties", "cache.properties"];
+
+pub fn find_cache_entries(gradle_home: &Path, maven_local: &Path) -> anyhow::Result<Vec<CacheEntry>> {
+    let shared_caches = gradle_home.join("caches");
+
+    let gradle_paths = [
+        find_dependency_entries(&shared_caches),
+        find_transform_entries(&shared_caches),
+        find_build_cache_entries(&shared_caches),
+    ]
+    .concat();
+
+    let maven_paths = find_maven_artifact_entries(&maven_local.join("repository"));
+
+    let entries = [
+        entries_for_paths(
+            &gradle_paths,
+            DiskCached::Shared(UserLevelDiskCache::GradleBuildCaching),
+        )?,
+        entries_for_paths(
+            &maven_paths,
+            DiskCached::Shared(UserLevelDiskCache::MavenLocalRepository),
+        )?,
+    ]
+    .concat();
+
+    Ok(entries)
+}
+
+pub fn entries_for_paths(paths: &[PathBuf], cached: DiskCached) -> anyhow::Result<Vec<CacheEntry>> {
+    paths
+        .iter()
+        .filter(|path| path.exists())
+        .map(|path| {
+            let (amount, last_used) = disk_usage_and_last_use(path)?;
+            Ok(CacheEntry::new(path.clone(), cached, amount, last_used))
+        })
+        .collect()
+}
+
+pub fn entries_within_folder(folder: &Path, cached: DiskCached) -> anyhow::Result<Vec<CacheEntry>> {
+    entries_for_paths(&children_at_depth(folder, 1), cached)
+}
+
+pub fn select_least_recently_used(entries: Vec<CacheEntry>, amount_to_free: ByteUnit) -> Vec<CacheEntry> {
+    let mut freed = ByteUnit::from(0);
+
+    entries
+        .into_iter()
+        .sorted_by_key(|entry| entry.last_used)
+        .take_while(|entry| {
+            let pending = freed < amount_to_free;
+            freed += entry.amount;
+            pending
+        })
+        .collect()
+}
+
+pub fn find_stale_cache_entries(
+    gradle_home: &Path,
+    maven_local: &Path,
+    max_age: Duration,
+) -> anyhow::Result<Vec<CacheEntry>> {
+    let threshold = SystemTime::now().checked_sub(max_age).unwrap_or(UNIX_EPOCH);
+
+    let stale = find_cache_entries(gradle_home, maven_local)?
+        .into_iter()
+        .filter(|entry| entry.last_used < threshold)
+        .collect();
+
+    Ok(stale)
+}
+
+// caches/modules-2/files-2.1/<group>/<module>/<version>
+fn find_dependency_entries(shared_caches: &Path) -> Vec<PathBuf> {
+    children_at_depth(&shared_caches.join("modules-2").join("files-2.1"), 3)
+}
+
+// caches/transforms-<n>/<hash> (up to Gradle 8.7) and caches/<version>/transforms/<hash> (Gradle 8.8+)
+fn find_transform_entries(shared_caches: &Path) -> Vec<PathBuf> {
+    children_at_depth(shared_caches, 1)
+        .into_iter()
+        .flat_map(|folder| {
+            let name = folder.file_name().unwrap_or_default().to_string_lossy().to_string();
+            if name.starts_with("transforms-") {
+                children_at_depth(&folder, 1)
+            } else {
+                children_at_depth(&folder.join("transforms"), 1)
+            }
+        })
+        .collect()
+}
+
+// caches/build-cache-<n>/<entry>
+fn find_build_cache_entries(shared_caches: &Path) -> Vec<PathBuf> {
+    children_at_depth(shared_caches, 1)
+        .into_iter()
+        .filter(|folder| {
+            folder
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("build-cache-"))
+        })
+        .flat_map(|folder| children_at_depth(&folder, 1))
+        .collect()
+}
+
+// .m2/repository/<group path>/<artifact>/<version>, identified by the POM file published along artifacts
+fn find_maven_artifact_entries(repository: &Path) -> Vec<PathBuf> {
+    WalkDir::new(repository)
+        .min_depth(1)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_dir())
+        .map(|entry| entry.into_path())
+        .filter(|folder| contains_pom_file(folder))
+        .sorted()
+        .collect()
+}
+
+fn contains_pom_file(folder: &Path) -> bool {
+    let Ok(children) = fs::read_dir(folder) else {
+        return false;
+    };
+
+    children
+        .filter_map(|child| child.ok())
+        .any(|child| child.path().extension().is_some_and(|extension| extension == "pom"))
+}
+
+fn children_at_depth(folder: &Path, depth: usize) -> Vec<PathBuf> {
+    WalkDir::new(folder)
+        .min_depth(depth)
+        .max_depth(depth)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.into_path())
+        .filter(|path| !is_bookkeeping_file(path))
+        .sorted()
+        .collect()
+}
+
+fn is_bookkeeping_file(path: &Path) -> bool {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    name.ends_with(".lock") || CACHE_BOOKKEEPING_FILES.contains(&name.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::disk::retention::{find_cache_entries, find_stale_cache_entries, select_least_recently_used};
+    use crate::core::models::{CacheEntry, DiskCached, ProjectLevelDiskCache, UserLevelDiskCache};
+    use std::fs;
+    use std::fs::{File, FileTimes};
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime};
+    use temp_dir::TempDir;
+    use ubyte::ToByteUnit;
+
+    const ONE_DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn create_file_used_days_ago(root: &Path, relative_path: &str, days: u64) -> PathBuf {
+        let path = root.join(relative_path);
+        fs::create_dir_all(path.parent().expect("Expecting a parent folder")).expect("Cant create fixture folder");
+        fs::write(&path, "foo").expect("Cant create fixture file");
+
+        let last_used = SystemTime::now() - ONE_DAY * days as u32;
+        let times = FileTimes::new().set_accessed(last_used).set_modified(last_used);
+        File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|file| file.set_times(times))
+            .expect("Cant set file times");
+
+        path
+    }
+
+    fn prepare_fake_caches(root: &Path) {
+        let files = [
+            (".gradle/caches/modules-2/modules-2.lock", 90),
+            (
+                ".gradle/caches/modules-2/files-2.1/com.squareup/okio/3.9.0/abcd/okio-3.9.0.jar",
+                90,
+            ),
+            (
+                ".gradle/caches/modules-2/files-2.1/com.squareup/okio/3.9.1/ef01/okio-3.9.1.jar",
+                2,
+            ),
+            (".gradle/caches/transforms-4/gc.properties", 90),
+            (".gradle/caches/transforms-4/1a2b/transformed/classes.jar", 45),
+            (".gradle/caches/8.10/transforms/3c4d/transformed/classes.jar", 1),
+            (".gradle/caches/build-cache-1/build-cache-1.lock", 90),
+            (".gradle/caches/build-cache-1/5e6f", 60),
+            (".gradle/caches/build-cache-1/7a8b", 3),
+            (".m2/repository/com/acme/widget/1.0.0/widget-1.0.0.pom", 120),
+            (".m2/repository/com/acme/widget/1.0.0/widget-1.0.0.jar", 120),
+            (".m2/repository/com/acme/widget/1.1.0/widget-1.1.0.pom", 120),
+            (".m2/repository/com/acme/widget/1.1.0/widget-1.1.0.jar", 5),
+        ];
+
+        for (file, days) in files {
+            create_file_used_days_ago(root, file, days);
+        }
+    }
+
+    #[test]
+    fn should_find_entries_from_shared_caches() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        prepare_fake_caches(temp_dir.path());
+
+        let gradle_home = temp_dir.path().join(".gradle");
+        let maven_local = temp_dir.path().join(".m2");
+
+        let entries = find_cache_entries(&gradle_home, &maven_local).expect("Cannot find cache entries");
+        let paths = entries.into_iter().map(|entry| entry.path).collect::<Vec<_>>();
+
+        let expected = [
+            ".gradle/caches/modules-2/files-2.1/com.squareup/okio/3.9.0",
+            ".gradle/caches/modules-2/files-2.1/com.squareup/okio/3.9.1",
+            ".gradle/caches/8.10/transforms/3c4d",
+            ".gradle/caches/transforms-4/1a2b",
+            ".gradle/caches/build-cache-1/5e6f",
+            ".gradle/caches/build-cache-1/7a8b",
+            ".m2/repository/com/acme/widget/1.0.0",
+            ".m2/repository/com/acme/widget/1.1.0",
+        ]
+        .into_iter()
+        .map(|relative| temp_dir.path().join(relative))
+        .collect::<Vec<_>>();
+
+        assert_eq!(paths, expected);
+    }
+
+    #[test]
+    fn should_find_only_entries_older_than_threshold() {
+        let temp_dir = TempDir::new().expect("Cant create temp dir");
+        prepare_fake_caches(temp_dir.path());
+
+        let gradle_home = temp_dir.path().join(".gradle");
+        let maven_local = temp_dir.path().join(".m2");
+
+        let stale = find_stale_cache_entries(&gradle_home, &maven_local, ONE_DAY * 30).expect("Cannot find entries");
+        let paths = stale.into_iter().map(|entry| entry.path).collect::<Vec<_>>();
+
+        let expected = [
+            ".gradle/caches/modules-2/files-2.1/com.squareup/okio/3.9.0",
+            ".gradle/caches/transforms-4/1a2b",
+            ".gradle/caches/build-cache-1/5e6f",
+            ".m2/repository/com/acme/widget/1.0.0",
+        ]
+        .into_iter()
+        .map(|relative| temp_dir.path().join(relative))
+        .collect::<Vec<_>>();
+
+        assert_eq!(paths, expected);
+    }
+
+    #[test]
+    fn should_select_least_recently_used_entries_until_freeing_enough_space() {
+        let now = SystemTime::now();
+        let build_cache = DiskCached::Shared(UserLevelDiskCache::GradleBuildCaching);
+        let build_output = DiskCached::Standalone(ProjectLevelDiskCache::BuildOutput);
+
+        let recent = CacheEntry::new(PathBuf::from("recent"), build_cache, 5.megabytes(), now);
+        let old = CacheEntry::new(PathBuf::from("old"), build_output, 2.megabytes(), now - ONE_DAY * 10);
+        let older = CacheEntry::new(PathBuf::from("older"), build_cache, 2.megabytes(), now - ONE_DAY * 20);
+        let entries = vec![recent, old.clone(), older.clone()];
+
+        let selected = select_least_recently_used(entries, 3.megabytes());
+
+        assert_eq!(selected, vec![older, old]);
+    }
+
+    #[test]
+    fn should_select_nothing_when_nothing_to_free() {
+        let build_cache = DiskCached::Shared(UserLevelDiskCache::GradleBuildCaching);
+        let entry = CacheEntry::new(PathBuf::from("entry"), build_cache, 5.megabytes(), SystemTime::now());
+
+        let selected = select_least_recently_used(vec![entry], 0.megabytes());
+
+        assert!(selected.is_empty());
+    }
+}
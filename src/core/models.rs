@@ -1,14 +1,27 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+use glob::Pattern;
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
 use ubyte::ByteUnit;
 
 #[derive(Debug)]
 pub enum MachineResource {
     RamMemory,
     DiskSpace,
+    AllResources,
+}
+
+impl MachineResource {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            MachineResource::RamMemory => "ram",
+            MachineResource::DiskSpace => "disk",
+            MachineResource::AllResources => "all",
+        }
+    }
 }
 
 impl Display for MachineResource {
@@ -16,17 +29,86 @@ impl Display for MachineResource {
         let formatted = match self {
             MachineResource::RamMemory => "RAM memory",
             MachineResource::DiskSpace => "disk space",
+            MachineResource::AllResources => "RAM memory and disk space",
         };
 
         formatter.write_str(formatted)
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectDiscovery {
+    pub roots: Vec<PathBuf>,
+    pub include: Vec<Pattern>,
+    pub exclude: Vec<Pattern>,
+    pub max_depth: Option<usize>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ExecutionOptions {
+    pub gradle_user_home: Option<PathBuf>,
+    pub dry_run: bool,
+    pub project_discovery: ProjectDiscovery,
+    pub per_project: bool,
+    pub per_process: bool,
+    pub older_than: Option<Duration>,
+    pub grace_period: Duration,
+    pub process_filters: ProcessFilters,
+    pub protection: ProcessProtection,
+    pub force: bool,
+    pub wait_for_builds: Option<Duration>,
+    pub stop_daemons: bool,
+    pub selection: Option<WipeSelection>,
+    pub disk_categories: CategoryFilter<DiskCached>,
+    pub memory_categories: CategoryFilter<MemoryCached>,
+    pub quarantine: bool,
+    pub quarantine_id: Option<String>,
+}
+
+// Narrows down the categories wiped by a given mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryFilter<T> {
+    pub only: Vec<T>,
+    pub except: Vec<T>,
+}
+
+impl<T> Default for CategoryFilter<T> {
+    fn default() -> Self {
+        Self {
+            only: vec![],
+            except: vec![],
+        }
+    }
+}
+
+impl<T: PartialEq> CategoryFilter<T> {
+    pub fn allows(&self, category: &T) -> bool {
+        (self.only.is_empty() || self.only.contains(category)) && !self.except.contains(category)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.only.is_empty() && self.except.is_empty()
+    }
+}
+
+// Caches picked by users, and projects whose own caches may be wiped
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WipeSelection {
+    pub caches: Vec<DiskCached>,
+    pub projects: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy)]
 pub enum WipeAction {
     Evaluate,
     ShallowWipe,
     DeepWipe,
+    BudgetWipe(ByteUnit),
+    SelectiveWipe,
+    PruneDistributions,
+    PruneToolchains,
+    RestoreQuarantine,
+    PurgeQuarantines,
 }
 
 impl Display for WipeAction {
@@ -35,6 +117,12 @@ impl Display for WipeAction {
             WipeAction::Evaluate => "evaluation",
             WipeAction::ShallowWipe => "shallow wiping",
             WipeAction::DeepWipe => "deep wiping",
+            WipeAction::BudgetWipe(_) => "budget wiping",
+            WipeAction::SelectiveWipe => "selective wiping",
+            WipeAction::PruneDistributions => "pruning of Gradle distributions",
+            WipeAction::PruneToolchains => "pruning of JDK toolchains",
+            WipeAction::RestoreQuarantine => "restoring of quarantined files",
+            WipeAction::PurgeQuarantines => "purging of quarantined files",
         };
 
         formatter.write_str(formatted)
@@ -44,15 +132,51 @@ impl Display for WipeAction {
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
 pub enum MemoryCached {
     GradleBuildDaemon,
+    GradleWorkerDaemon,
     KotlinCompilerDaemon,
+    MavenDaemon,
+    BazelServer,
+    LanguageServer,
+    IntegratedDevelopmentEnvironment,
     OtherJavaProcess,
 }
 
+impl MemoryCached {
+    pub const ALL: [MemoryCached; 8] = [
+        MemoryCached::GradleBuildDaemon,
+        MemoryCached::GradleWorkerDaemon,
+        MemoryCached::KotlinCompilerDaemon,
+        MemoryCached::MavenDaemon,
+        MemoryCached::BazelServer,
+        MemoryCached::LanguageServer,
+        MemoryCached::IntegratedDevelopmentEnvironment,
+        MemoryCached::OtherJavaProcess,
+    ];
+
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            MemoryCached::GradleBuildDaemon => "gradle-daemon",
+            MemoryCached::GradleWorkerDaemon => "gradle-worker",
+            MemoryCached::KotlinCompilerDaemon => "kotlin-daemon",
+            MemoryCached::MavenDaemon => "maven-daemon",
+            MemoryCached::BazelServer => "bazel-server",
+            MemoryCached::LanguageServer => "language-server",
+            MemoryCached::IntegratedDevelopmentEnvironment => "ide",
+            MemoryCached::OtherJavaProcess => "other-jvm",
+        }
+    }
+}
+
 impl Display for MemoryCached {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         let name = match self {
             MemoryCached::GradleBuildDaemon => "Gradle Daemon",
+            MemoryCached::GradleWorkerDaemon => "Gradle Worker",
             MemoryCached::KotlinCompilerDaemon => "Kotlin Daemon",
+            MemoryCached::MavenDaemon => "Maven Daemon",
+            MemoryCached::BazelServer => "Bazel Server",
+            MemoryCached::LanguageServer => "Language Server",
+            MemoryCached::IntegratedDevelopmentEnvironment => "IDE",
             MemoryCached::OtherJavaProcess => "Other JVM process",
         };
 
@@ -96,19 +220,63 @@ impl Display for UserLevelDiskCache {
 }
 
 impl UserLevelDiskCache {
+    pub const ALL: [UserLevelDiskCache; 11] = [
+        UserLevelDiskCache::GradleConfigurationCaching,
+        UserLevelDiskCache::GradleBuildCaching,
+        UserLevelDiskCache::GradleDaemonLogs,
+        UserLevelDiskCache::GradleJDKToolchains,
+        UserLevelDiskCache::GradleDistributions,
+        UserLevelDiskCache::GradleTemporaryFiles,
+        UserLevelDiskCache::GradleNativeFiles,
+        UserLevelDiskCache::GradleBuildScans,
+        UserLevelDiskCache::GradleOtherCaches,
+        UserLevelDiskCache::MavenLocalRepository,
+        UserLevelDiskCache::KonanCaches,
+    ];
+
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            UserLevelDiskCache::GradleConfigurationCaching => "config-cache",
+            UserLevelDiskCache::GradleBuildCaching => "build-cache",
+            UserLevelDiskCache::GradleDaemonLogs => "daemon-logs",
+            UserLevelDiskCache::GradleJDKToolchains => "jdk-toolchains",
+            UserLevelDiskCache::GradleDistributions => "distributions",
+            UserLevelDiskCache::GradleTemporaryFiles => "temporary-files",
+            UserLevelDiskCache::GradleNativeFiles => "native-files",
+            UserLevelDiskCache::GradleBuildScans => "build-scans",
+            UserLevelDiskCache::GradleOtherCaches => "other-caches",
+            UserLevelDiskCache::MavenLocalRepository => "maven-local",
+            UserLevelDiskCache::KonanCaches => "konan-caches",
+        }
+    }
+
+    pub fn path_relative_to_gradle_home(&self) -> Option<PathBuf> {
+        let raw_path = match self {
+            UserLevelDiskCache::GradleConfigurationCaching => "configuration-cache",
+            UserLevelDiskCache::GradleBuildCaching => "caches",
+            UserLevelDiskCache::GradleDaemonLogs => "daemon",
+            UserLevelDiskCache::GradleJDKToolchains => "jdks",
+            UserLevelDiskCache::GradleDistributions => "wrapper",
+            UserLevelDiskCache::GradleTemporaryFiles => ".tmp",
+            UserLevelDiskCache::GradleNativeFiles => "native",
+            UserLevelDiskCache::GradleBuildScans => "build-scan-data",
+            UserLevelDiskCache::MavenLocalRepository
+            | UserLevelDiskCache::KonanCaches
+            | UserLevelDiskCache::GradleOtherCaches => "",
+        };
+
+        if raw_path.is_empty() {
+            return None;
+        };
+
+        Some(PathBuf::from(raw_path))
+    }
+
     pub fn path_relative_to_user_home(&self) -> Option<PathBuf> {
         let raw_path = match self {
-            UserLevelDiskCache::GradleConfigurationCaching => ".gradle/configuration-cache",
-            UserLevelDiskCache::GradleBuildCaching => ".gradle/caches",
-            UserLevelDiskCache::GradleDaemonLogs => ".gradle/daemon",
-            UserLevelDiskCache::GradleJDKToolchains => ".gradle/jdks",
-            UserLevelDiskCache::GradleDistributions => ".gradle/wrapper",
-            UserLevelDiskCache::GradleTemporaryFiles => ".gradle/.tmp",
-            UserLevelDiskCache::GradleNativeFiles => ".gradle/native",
-            UserLevelDiskCache::GradleBuildScans => ".gradle/build-scan-data",
             UserLevelDiskCache::MavenLocalRepository => ".m2",
             UserLevelDiskCache::KonanCaches => ".konan",
-            UserLevelDiskCache::GradleOtherCaches => "",
+            _ => "",
         };
 
         if raw_path.is_empty() {
@@ -126,6 +294,22 @@ pub enum ProjectLevelDiskCache {
     IdeaMetadata,
 }
 
+impl ProjectLevelDiskCache {
+    pub const ALL: [ProjectLevelDiskCache; 3] = [
+        ProjectLevelDiskCache::BuildOutput,
+        ProjectLevelDiskCache::GradleMetadata,
+        ProjectLevelDiskCache::IdeaMetadata,
+    ];
+
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            ProjectLevelDiskCache::BuildOutput => "project-build",
+            ProjectLevelDiskCache::GradleMetadata => "project-gradle",
+            ProjectLevelDiskCache::IdeaMetadata => "project-idea",
+        }
+    }
+}
+
 impl Display for ProjectLevelDiskCache {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         let name = match self {
@@ -144,6 +328,29 @@ pub enum DiskCached {
     Standalone(ProjectLevelDiskCache),
 }
 
+impl DiskCached {
+    pub fn all() -> Vec<DiskCached> {
+        UserLevelDiskCache::ALL
+            .into_iter()
+            .map(DiskCached::Shared)
+            .chain(ProjectLevelDiskCache::ALL.into_iter().map(DiskCached::Standalone))
+            .collect::<Vec<_>>()
+    }
+
+    pub fn from_identifier(identifier: &str) -> Option<DiskCached> {
+        DiskCached::all()
+            .into_iter()
+            .find(|cached| cached.identifier() == identifier)
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            DiskCached::Shared(user_level) => user_level.identifier(),
+            DiskCached::Standalone(project_level) => project_level.identifier(),
+        }
+    }
+}
+
 impl Display for DiskCached {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         let name = match self {
@@ -161,6 +368,15 @@ pub enum UseCase {
     Memory(MemoryCached),
 }
 
+impl UseCase {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            UseCase::Disk(disk_cached) => disk_cached.identifier(),
+            UseCase::Memory(memory_cached) => memory_cached.identifier(),
+        }
+    }
+}
+
 impl Display for UseCase {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
         let inner = match self {
@@ -202,37 +418,601 @@ impl AllocatedResource {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum WipeTarget {
+    Path(PathBuf),
+    Process { pid: u32, launcher: String },
+}
+
+impl Display for WipeTarget {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WipeTarget::Path(path) => formatter.write_str(&path.to_string_lossy()),
+            WipeTarget::Process { pid, launcher } => write!(formatter, "PID {pid} ({launcher})"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReclaimableResource {
+    pub target: WipeTarget,
+    pub use_case: UseCase,
+    pub amount: ByteUnit,
+}
+
+impl ReclaimableResource {
+    pub fn new(target: WipeTarget, use_case: UseCase, amount: ByteUnit) -> Self {
+        Self {
+            target,
+            use_case,
+            amount,
+        }
+    }
+}
+
+pub struct DryRunOutcome {
+    pub resources: Vec<ReclaimableResource>,
+    pub total_size: ByteUnit,
+}
+
+impl DryRunOutcome {
+    pub fn new(resources: Vec<ReclaimableResource>, total_size: ByteUnit) -> Self {
+        Self { resources, total_size }
+    }
+}
+
+// State of a Gradle daemon, as recorded in its daemon registry
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DaemonState {
+    Idle,
+    Busy,
+    Canceled,
+    // Either broken, asked to stop or already stopped
+    Stopped,
+}
+
+impl DaemonState {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            DaemonState::Idle => "idle",
+            DaemonState::Busy => "busy",
+            DaemonState::Canceled => "canceled",
+            DaemonState::Stopped => "stopped",
+        }
+    }
+}
+
+// Gradle records the ordinal of its daemon states (Idle, Busy, Canceled, Broken, StopRequested, Stopped, ForceStopped)
+impl TryFrom<u8> for DaemonState {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let state = match value {
+            0 => DaemonState::Idle,
+            1 => DaemonState::Busy,
+            2 => DaemonState::Canceled,
+            3..=6 => DaemonState::Stopped,
+            other => anyhow::bail!("unexpected daemon state {other}"),
+        };
+
+        Ok(state)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct JvmProcess {
+    pub pid: u32,
+    pub java_command: String,
+    pub java_version: Option<String>,
+    pub heap_capacity: ByteUnit,
+    pub heap_used: ByteUnit,
+    pub metaspace_capacity: ByteUnit,
+    pub metaspace_used: ByteUnit,
+    pub uptime: Duration,
+    pub daemon_state: Option<DaemonState>,
+}
+
+impl JvmProcess {
+    // The main class (or jar) used to launch this process, without its arguments
+    pub fn launcher(&self) -> &str {
+        self.java_command.split_whitespace().next().unwrap_or_default()
+    }
+
+    // Gradle daemons get their version as argument, while Kotlin daemons carry it in the compiler classpath
+    pub fn tool_version(&self) -> Option<&str> {
+        const KOTLIN_COMPILER_ARTIFACT: &str = "kotlin-compiler-embeddable-";
+
+        let mut tokens = self.java_command.split_whitespace();
+
+        match tokens.next()? {
+            launcher if launcher.ends_with("GradleDaemon") => tokens.next(),
+            launcher if launcher.ends_with("KotlinCompileDaemon") => {
+                let start = self.java_command.find(KOTLIN_COMPILER_ARTIFACT)? + KOTLIN_COMPILER_ARTIFACT.len();
+                let (version, _) = self.java_command[start..].split_once(".jar")?;
+                Some(version)
+            },
+            _ => None,
+        }
+    }
+}
+
+// Narrows down which JVM processes a RAM wipe stops; all processes of the wiped categories otherwise
+#[derive(Debug, Default, Clone)]
+pub struct ProcessFilters {
+    pub idle_for: Option<Duration>,
+    pub older_than: Option<Duration>,
+    pub gradle_version: Option<String>,
+}
+
+impl ProcessFilters {
+    pub fn needs_activity(&self) -> bool {
+        self.idle_for.is_some() || self.older_than.is_some()
+    }
+}
+
+// How long a process has been running, and since when it waits for work (only known for Gradle daemons)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ProcessActivity {
+    pub uptime: Duration,
+    pub idle_since: Option<SystemTime>,
+}
+
+// What the operating system knows about a running process
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProcessStats {
+    pub rss: ByteUnit,
+    pub uptime: Duration,
+    pub working_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProcessUsage {
+    pub pid: u32,
+    pub use_case: UseCase,
+    pub tool_version: Option<String>,
+    pub java_version: Option<String>,
+    pub daemon_state: Option<DaemonState>,
+    pub stats: ProcessStats,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ProcessShutdown {
+    Graceful,
+    Forced,
+}
+
+impl ProcessShutdown {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            ProcessShutdown::Graceful => "graceful",
+            ProcessShutdown::Forced => "forced",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct StoppedProcess {
+    pub pid: u32,
+    pub launcher: String,
+    pub shutdown: ProcessShutdown,
+}
+
+impl StoppedProcess {
+    pub fn new(pid: u32, launcher: String, shutdown: ProcessShutdown) -> Self {
+        Self {
+            pid,
+            launcher,
+            shutdown,
+        }
+    }
+}
+
+// A process spared by a RAM wipe, along with why
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProtectedProcess {
+    pub pid: u32,
+    pub launcher: String,
+    pub reason: String,
+}
+
+impl ProtectedProcess {
+    pub fn new(pid: u32, launcher: String, reason: String) -> Self {
+        Self { pid, launcher, reason }
+    }
+}
+
+// Processes that RAM wipes never stop, matched by launcher (glob), PID or process name
+#[derive(Debug, Default, Clone)]
+pub struct ProcessProtection {
+    pub launchers: Vec<Pattern>,
+    pub pids: Vec<u32>,
+    pub names: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RemovalStatus {
+    Removed,
+    NotPresent,
+    PermissionDenied,
+    PartiallyRemoved(ByteUnit),
+    Failed(String),
+}
+
+impl RemovalStatus {
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            RemovalStatus::Removed => "removed",
+            RemovalStatus::NotPresent => "not-present",
+            RemovalStatus::PermissionDenied => "permission-denied",
+            RemovalStatus::PartiallyRemoved(_) => "partially-removed",
+            RemovalStatus::Failed(_) => "failed",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, RemovalStatus::Removed | RemovalStatus::NotPresent)
+    }
+}
+
+impl Display for RemovalStatus {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemovalStatus::Removed => formatter.write_str("removed"),
+            RemovalStatus::NotPresent => formatter.write_str("not present"),
+            RemovalStatus::PermissionDenied => formatter.write_str("permission denied"),
+            RemovalStatus::PartiallyRemoved(remaining) => write!(formatter, "partially removed, {remaining} left"),
+            RemovalStatus::Failed(reason) => write!(formatter, "failed, {reason}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PathRemoval {
+    pub path: PathBuf,
+    pub status: RemovalStatus,
+}
+
+impl PathRemoval {
+    pub fn new(path: PathBuf, status: RemovalStatus) -> Self {
+        Self { path, status }
+    }
+
+    pub fn failure(&self) -> Option<String> {
+        self.status
+            .is_failure()
+            .then(|| format!("{} : {}", self.path.to_string_lossy(), self.status))
+    }
+}
+
 pub struct WipingOutcome {
     pub reclaimed: ByteUnit,
+    pub breakdown: Vec<AllocatedResource>,
+    pub failures: Vec<String>,
+    pub stopped_processes: Vec<StoppedProcess>,
+    pub protected_processes: Vec<ProtectedProcess>,
+    pub quarantine: Option<String>,
+    pub quarantined: ByteUnit,
+    pub removals: Vec<PathRemoval>,
 }
 
 impl WipingOutcome {
-    pub fn new(reclaimed: ByteUnit) -> Self {
-        Self { reclaimed }
+    pub fn new(reclaimed: ByteUnit, breakdown: Vec<AllocatedResource>, failures: Vec<String>) -> Self {
+        Self {
+            reclaimed,
+            breakdown,
+            failures,
+            stopped_processes: vec![],
+            protected_processes: vec![],
+            quarantine: None,
+            quarantined: ByteUnit::from(0),
+            removals: vec![],
+        }
+    }
+
+    pub fn with_removals(self, removals: Vec<PathRemoval>) -> Self {
+        Self { removals, ..self }
+    }
+
+    // Quarantined files still take disk space until purged, hence they are not reclaimed yet
+    pub fn with_quarantine(self, quarantine: Option<String>, quarantined: ByteUnit) -> Self {
+        Self {
+            quarantine,
+            quarantined,
+            ..self
+        }
+    }
+
+    pub fn with_stopped_processes(self, stopped_processes: Vec<StoppedProcess>) -> Self {
+        Self {
+            stopped_processes,
+            ..self
+        }
+    }
+
+    pub fn with_protected_processes(self, protected_processes: Vec<ProtectedProcess>) -> Self {
+        Self {
+            protected_processes,
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ToolchainRequest {
+    pub major_version: u32,
+    pub vendor: Option<String>,
+}
+
+impl ToolchainRequest {
+    pub fn new(major_version: u32, vendor: Option<String>) -> Self {
+        Self { major_version, vendor }
+    }
+
+    pub fn matches(&self, installed: &InstalledToolchain) -> bool {
+        let same_vendor = match (&self.vendor, &installed.vendor) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(requested), Some(provisioned)) => {
+                normalize_vendor(provisioned).contains(&normalize_vendor(requested))
+            },
+        };
+
+        self.major_version == installed.major_version && same_vendor
+    }
+}
+
+// Vendors show up as JvmVendorSpec constants (GRAAL_VM), release metadata (GraalVM Community) or folder names
+fn normalize_vendor(vendor: &str) -> String {
+    vendor
+        .chars()
+        .filter(|character| character.is_ascii_alphanumeric())
+        .map(|character| character.to_ascii_lowercase())
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InstalledToolchain {
+    pub path: PathBuf,
+    pub major_version: u32,
+    pub vendor: Option<String>,
+}
+
+impl InstalledToolchain {
+    pub fn new(path: PathBuf, major_version: u32, vendor: Option<String>) -> Self {
+        Self {
+            path,
+            major_version,
+            vendor,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct PrunedResource {
+    pub path: PathBuf,
+    pub use_case: UseCase,
+    pub amount: ByteUnit,
+    pub kept: bool,
+}
+
+impl PrunedResource {
+    pub fn new(path: PathBuf, use_case: UseCase, amount: ByteUnit, kept: bool) -> Self {
+        Self {
+            path,
+            use_case,
+            amount,
+            kept,
+        }
+    }
+}
+
+pub struct PruningOutcome {
+    pub resources: Vec<PrunedResource>,
+    pub reclaimed: ByteUnit,
+    pub failures: Vec<String>,
+    pub quarantine: Option<String>,
+    pub quarantined: ByteUnit,
+    pub removals: Vec<PathRemoval>,
+}
+
+impl PruningOutcome {
+    pub fn new(resources: Vec<PrunedResource>, reclaimed: ByteUnit, failures: Vec<String>) -> Self {
+        Self {
+            resources,
+            reclaimed,
+            failures,
+            quarantine: None,
+            quarantined: ByteUnit::from(0),
+            removals: vec![],
+        }
+    }
+
+    pub fn with_removals(self, removals: Vec<PathRemoval>) -> Self {
+        Self { removals, ..self }
+    }
+
+    // Quarantined files still take disk space until purged, hence they are not reclaimed yet
+    pub fn with_quarantine(self, quarantine: Option<String>, quarantined: ByteUnit) -> Self {
+        Self {
+            quarantine,
+            quarantined,
+            ..self
+        }
+    }
+}
+
+pub struct RestoringOutcome {
+    pub quarantine: String,
+    pub restored: Vec<PathBuf>,
+    pub failures: Vec<String>,
+}
+
+impl RestoringOutcome {
+    pub fn new(quarantine: String, restored: Vec<PathBuf>, failures: Vec<String>) -> Self {
+        Self {
+            quarantine,
+            restored,
+            failures,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProjectUsage {
+    pub project: PathBuf,
+    pub build_output: ByteUnit,
+    pub gradle_metadata: ByteUnit,
+    pub idea_metadata: ByteUnit,
+}
+
+impl ProjectUsage {
+    pub fn new(project: PathBuf, build_output: ByteUnit, gradle_metadata: ByteUnit, idea_metadata: ByteUnit) -> Self {
+        Self {
+            project,
+            build_output,
+            gradle_metadata,
+            idea_metadata,
+        }
+    }
+
+    pub fn total(&self) -> ByteUnit {
+        self.build_output + self.gradle_metadata + self.idea_metadata
+    }
+
+    pub fn amount(&self, cached: ProjectLevelDiskCache) -> ByteUnit {
+        match cached {
+            ProjectLevelDiskCache::BuildOutput => self.build_output,
+            ProjectLevelDiskCache::GradleMetadata => self.gradle_metadata,
+            ProjectLevelDiskCache::IdeaMetadata => self.idea_metadata,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CacheEntry {
+    pub path: PathBuf,
+    pub cached: DiskCached,
+    pub amount: ByteUnit,
+    pub last_used: SystemTime,
+}
+
+impl CacheEntry {
+    pub fn new(path: PathBuf, cached: DiskCached, amount: ByteUnit, last_used: SystemTime) -> Self {
+        Self {
+            path,
+            cached,
+            amount,
+            last_used,
+        }
+    }
+}
+
+// Committed (capacity) and used JVM memory, as reported by perfdata counters
+#[derive(Debug, PartialEq, Eq)]
+pub struct JvmMemoryUsage {
+    pub use_case: UseCase,
+    pub heap_committed: ByteUnit,
+    pub heap_used: ByteUnit,
+    pub metaspace_committed: ByteUnit,
+    pub metaspace_used: ByteUnit,
+}
+
+impl JvmMemoryUsage {
+    pub fn new(
+        use_case: UseCase,
+        heap_committed: ByteUnit,
+        heap_used: ByteUnit,
+        metaspace_committed: ByteUnit,
+        metaspace_used: ByteUnit,
+    ) -> Self {
+        Self {
+            use_case,
+            heap_committed,
+            heap_used,
+            metaspace_committed,
+            metaspace_used,
+        }
     }
 }
 
 pub struct EvaluationOutcome {
     pub resources: Vec<AllocatedResource>,
     pub total_size: ByteUnit,
+    pub projects: Vec<ProjectUsage>,
+    pub jvm_memory: Vec<JvmMemoryUsage>,
+    pub processes: Vec<ProcessUsage>,
 }
 
 impl EvaluationOutcome {
     pub fn new(resources: Vec<AllocatedResource>, total_size: ByteUnit) -> Self {
-        Self { resources, total_size }
+        Self {
+            resources,
+            total_size,
+            projects: vec![],
+            jvm_memory: vec![],
+            processes: vec![],
+        }
+    }
+
+    pub fn with_projects(self, projects: Vec<ProjectUsage>) -> Self {
+        Self { projects, ..self }
+    }
+
+    pub fn with_jvm_memory(self, jvm_memory: Vec<JvmMemoryUsage>) -> Self {
+        Self { jvm_memory, ..self }
+    }
+
+    pub fn with_processes(self, processes: Vec<ProcessUsage>) -> Self {
+        Self { processes, ..self }
+    }
+}
+
+// Outcomes of operations that span several machine resources, in the order they were executed
+pub struct CombinedOutcome {
+    pub sections: Vec<(MachineResource, ExecutionOutcome)>,
+}
+
+impl CombinedOutcome {
+    pub fn new(sections: Vec<(MachineResource, ExecutionOutcome)>) -> Self {
+        Self { sections }
+    }
+
+    pub fn totals(&self) -> Vec<(&MachineResource, ByteUnit)> {
+        self.sections
+            .iter()
+            .filter_map(|(resource, outcome)| outcome.total().map(|amount| (resource, amount)))
+            .collect::<Vec<_>>()
     }
 }
 
 pub enum ExecutionOutcome {
     Evaluation(EvaluationOutcome),
     Wiping(WipingOutcome),
+    DryRun(DryRunOutcome),
+    Pruning(PruningOutcome),
+    Restoring(RestoringOutcome),
+    Combined(CombinedOutcome),
 }
 
 impl ExecutionOutcome {
-    pub fn as_evaluation(&self) -> &EvaluationOutcome {
+    pub fn has_failures(&self) -> bool {
+        match self {
+            ExecutionOutcome::Evaluation(_) | ExecutionOutcome::DryRun(_) => false,
+            ExecutionOutcome::Wiping(wiping) => !wiping.failures.is_empty(),
+            ExecutionOutcome::Pruning(pruning) => !pruning.failures.is_empty(),
+            ExecutionOutcome::Restoring(restoring) => !restoring.failures.is_empty(),
+            ExecutionOutcome::Combined(combined) => combined.sections.iter().any(|(_, outcome)| outcome.has_failures()),
+        }
+    }
+
+    // Evaluated or reclaimed amount, depending on the operation; amounts of different resources don't add up
+    pub fn total(&self) -> Option<ByteUnit> {
         match self {
-            ExecutionOutcome::Evaluation(evaluation) => evaluation,
-            ExecutionOutcome::Wiping(_) => panic!("Not an evaluation"),
+            ExecutionOutcome::Evaluation(evaluation) => Some(evaluation.total_size),
+            ExecutionOutcome::Wiping(wiping) => Some(wiping.reclaimed),
+            ExecutionOutcome::DryRun(dry_run) => Some(dry_run.total_size),
+            ExecutionOutcome::Pruning(pruning) => Some(pruning.reclaimed),
+            ExecutionOutcome::Restoring(_) | ExecutionOutcome::Combined(_) => None,
         }
     }
 }
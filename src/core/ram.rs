@@ -2,11 +2,15 @@
 // SPDX-License-Identifier: MIT
 
 mod jps;
+mod perfdata;
+mod registry;
 mod resources;
 mod wiping;
 
 use std::path::PathBuf;
 
+pub use registry::find_daemon_states;
+pub use registry::find_idle_daemons;
 pub use resources::*;
 pub use wiping::*;
 
@@ -1,77 +1,155 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
+use crate::core::models::JvmProcess;
+use crate::core::ram::perfdata::PerfData;
+use itertools::Itertools;
 use log::debug;
-use rust_strings::BytesConfig;
 use std::fs;
 use std::path::{Path, PathBuf};
+use ubyte::ByteUnit;
 
-pub fn find_jvm_processes(hsperfdata_locator: fn() -> PathBuf) -> anyhow::Result<Vec<(u32, String)>> {
+pub fn find_jvm_processes(hsperfdata_locator: fn() -> PathBuf) -> anyhow::Result<Vec<JvmProcess>> {
     let jvm_perf_data_path = hsperfdata_locator();
 
-    let jps_paths = fs::read_dir(jvm_perf_data_path)?
+    let processes = fs::read_dir(jvm_perf_data_path)?
         .filter_map(|entry| entry.ok())
         .map(|entry| entry.path())
-        .collect::<Vec<_>>();
-
-    let pids = jps_paths
-        .iter()
-        .map(|pathbuf| pathbuf.as_path())
-        .map(pid_from_jps_file)
-        .collect::<Vec<_>>();
-
-    let launcher_class_names = jps_paths
-        .iter()
-        .map(|pathbuf| pathbuf.as_path())
-        .map(launcher_class_from_monitored_process)
-        .collect::<Vec<_>>();
-
-    let processes = pids
-        .into_iter()
-        .zip(launcher_class_names)
-        .filter(|(_, class_name)| !class_name.contains("sun"))
+        .sorted()
+        .filter_map(|path| monitored_process(&path))
+        .filter(|process| !process.launcher().is_empty() && !process.launcher().contains("sun.tools."))
         .collect::<Vec<_>>();
 
     debug!("");
     debug!("Listing launcher packages and PIDs associated with JVM processes:");
     debug!("");
 
-    processes
-        .iter()
-        .for_each(|(pid, launcher)| debug!("Launcher class : {launcher} ({pid})"));
+    processes.iter().for_each(|process| {
+        debug!(
+            "Launcher class : {} ({}) | Java {} | heap {} of {} | metaspace {} of {} | up for {}s",
+            process.launcher(),
+            process.pid,
+            process.java_version.as_deref().unwrap_or("unknown"),
+            process.heap_used,
+            process.heap_capacity,
+            process.metaspace_used,
+            process.metaspace_capacity,
+            process.uptime.as_secs()
+        )
+    });
 
     debug!("");
 
     Ok(processes)
 }
 
-fn pid_from_jps_file(path_to_file: &Path) -> u32 {
+// Files may belong to processes from other JVM vendors or be half-written, so they are skipped
+fn monitored_process(path_to_file: &Path) -> Option<JvmProcess> {
     debug!("Evaluating JPS-related files at : {}", path_to_file.to_string_lossy());
-    let file_path_raw_str = path_to_file.file_name().expect("Not a valid path").to_string_lossy();
-    file_path_raw_str.parse::<u32>().expect("Cannot parse filename as u32")
+
+    let pid = path_to_file.file_name()?.to_string_lossy().parse::<u32>().ok()?;
+
+    let perf_data = PerfData::read(path_to_file)
+        .inspect_err(|error| debug!("Skipping {pid} : {error:#}"))
+        .ok()?;
+
+    let process = JvmProcess {
+        pid,
+        java_command: perf_data.text("sun.rt.javaCommand").unwrap_or_default().to_string(),
+        java_version: perf_data.text("java.property.java.version").map(String::from),
+        heap_capacity: sum_generation_counters(&perf_data, |name| {
+            name.ends_with(".capacity") && !name.contains(".space.")
+        }),
+        heap_used: sum_generation_counters(&perf_data, |name| name.ends_with(".used")),
+        metaspace_capacity: perf_data.bytes("sun.gc.metaspace.capacity").unwrap_or_default(),
+        metaspace_used: perf_data.bytes("sun.gc.metaspace.used").unwrap_or_default(),
+        uptime: perf_data.ticks("sun.os.hrt.ticks").unwrap_or_default(),
+        daemon_state: None,
+    };
+
+    Some(process)
 }
 
-fn launcher_class_from_monitored_process(path_to_file: &Path) -> String {
-    debug!("Parsing file : {}", path_to_file.to_string_lossy());
+// Heap is split into generations (young, old), each one made of spaces (eden, survivors, tenured)
+fn sum_generation_counters(perf_data: &PerfData, selector: fn(&str) -> bool) -> ByteUnit {
+    perf_data
+        .names()
+        .filter(|name| name.starts_with("sun.gc.generation.") && selector(name))
+        .filter_map(|name| perf_data.bytes(name))
+        .fold(ByteUnit::from(0), |total, amount| total + amount)
+}
 
-    let file_contents_as_bytes = fs::read(path_to_file).expect("Cannot read jps binary file");
-    let parsing_config = BytesConfig::new(file_contents_as_bytes);
-    let parsed_binary_info = rust_strings::strings(&parsing_config).expect("Cannot parse jps binary");
-    let parsed_strings = parsed_binary_info
-        .into_iter()
-        .map(|(parsed, _)| parsed)
-        .collect::<Vec<_>>();
+#[cfg(test)]
+mod tests {
+    use crate::core::models::JvmProcess;
+    use crate::core::ram::jps::find_jvm_processes;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use ubyte::ByteUnit;
+
+    fn locate_fake_hsperdata() -> PathBuf {
+        let root_dir = std::env::current_dir().expect("Cannot find current dir");
+        root_dir.join("test-data").join("hsperf")
+    }
+
+    #[test]
+    fn should_find_jvm_processes_from_hsperfdata() {
+        let processes = find_jvm_processes(locate_fake_hsperdata).expect("Cannot find JVM processes");
+
+        let launchers = processes
+            .iter()
+            .map(|process| (process.pid, process.launcher()))
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            (45927, "org.gradle.launcher.daemon.bootstrap.GradleDaemon"),
+            (49362, "org.jetbrains.kotlin.daemon.KotlinCompileDaemon"),
+            (49739, "org.gradle.launcher.daemon.bootstrap.GradleDaemon"),
+            (50045, "org.gradle.launcher.daemon.bootstrap.GradleDaemon"),
+            (50050, "org.jetbrains.kotlin.daemon.KotlinCompileDaemon"),
+            (70918, "com.intellij.idea.Main"),
+        ];
+
+        assert_eq!(launchers, expected);
+    }
+
+    #[test]
+    fn should_describe_jvm_process_from_hsperfdata() {
+        let processes = find_jvm_processes(locate_fake_hsperdata).expect("Cannot find JVM processes");
+
+        let expected = JvmProcess {
+            pid: 45927,
+            java_command: String::from("org.gradle.launcher.daemon.bootstrap.GradleDaemon 8.7"),
+            java_version: Some(String::from("21")),
+            heap_capacity: ByteUnit::from(3502243840u64),
+            heap_used: ByteUnit::from(1038560376u64),
+            metaspace_capacity: ByteUnit::from(304414720u64),
+            metaspace_used: ByteUnit::from(300703600u64),
+            uptime: Duration::from_nanos(8057374588750),
+            daemon_state: None,
+        };
+
+        assert_eq!(processes.first(), Some(&expected));
+    }
+
+    #[test]
+    fn should_extract_tool_versions_from_java_command() {
+        let processes = find_jvm_processes(locate_fake_hsperdata).expect("Cannot find JVM processes");
+
+        let versions = processes
+            .iter()
+            .map(|process| (process.pid, process.tool_version()))
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            (45927, Some("8.7")),
+            (49362, Some("1.9.23")),
+            (49739, Some("8.4")),
+            (50045, Some("8.2")),
+            (50050, Some("1.8.20")),
+            (70918, None),
+        ];
 
-    let (java_cmd_index, _) = parsed_strings
-        .iter()
-        .enumerate()
-        .find(|(_, cmd)| cmd.contains("sun.rt.javaCommand"))
-        .expect("Cannot find javaCommand");
-
-    let launcher_class_name = parsed_strings.get(java_cmd_index + 1).expect("Cannot get javaCommand");
-    let sanitized_command_name = launcher_class_name
-        .split_whitespace()
-        .next()
-        .expect("Cannot extract sanitized process name");
-    String::from(sanitized_command_name)
+        assert_eq!(versions, expected);
+    }
 }
@@ -0,0 +1,257 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+// Parser for the HotSpot performance data (hsperfdata) format, the same one read by jps and jstat
+// https://github.com/openjdk/jdk/blob/master/src/hotspot/share/runtime/perfMemory.hpp
+// https://github.com/openjdk/jdk/blob/master/src/hotspot/share/runtime/perfData.hpp
+
+use anyhow::{Context, bail, ensure};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use ubyte::ByteUnit;
+
+const PERFDATA_MAGIC: u32 = 0xcafec0c0;
+const PERFDATA_MAJOR_VERSION: u8 = 2;
+const PROLOGUE_SIZE: usize = 32;
+const ENTRY_HEADER_SIZE: usize = 20;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PerfDataUnits {
+    None,
+    Bytes,
+    Ticks,
+    Events,
+    String,
+    Hertz,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PerfDataValue {
+    Long(i64),
+    Text(String),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PerfDataEntry {
+    pub units: PerfDataUnits,
+    pub value: PerfDataValue,
+}
+
+#[derive(Debug)]
+pub struct PerfData {
+    entries: HashMap<String, PerfDataEntry>,
+}
+
+impl PerfData {
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let bytes = fs::read(path).with_context(|| format!("Cannot read {}", path.to_string_lossy()))?;
+        PerfData::parse(&bytes).with_context(|| format!("Invalid hsperfdata at {}", path.to_string_lossy()))
+    }
+
+    pub fn parse(bytes: &[u8]) -> anyhow::Result<Self> {
+        ensure!(bytes.len() >= PROLOGUE_SIZE, "truncated prologue");
+
+        // The magic number is always stored as big-endian
+        let magic = u32::from_be_bytes(bytes[0..4].try_into()?);
+        ensure!(magic == PERFDATA_MAGIC, "unexpected magic number {magic:#x}");
+
+        let little_endian = match bytes[4] {
+            0 => false,
+            1 => true,
+            other => bail!("unexpected byte order {other}"),
+        };
+
+        let (major, minor) = (bytes[5], bytes[6]);
+        ensure!(major == PERFDATA_MAJOR_VERSION, "unsupported version {major}.{minor}");
+        ensure!(bytes[7] != 0, "not accessible yet");
+
+        let reader = Reader { bytes, little_endian };
+        let used = reader.u32(8)? as usize;
+        let entries_offset = reader.u32(24)? as usize;
+        let entries_count = reader.u32(28)?;
+        let region = &bytes[..used.min(bytes.len())];
+        let reader = Reader {
+            bytes: region,
+            little_endian,
+        };
+
+        let mut entries = HashMap::new();
+        let mut offset = entries_offset;
+
+        for _ in 0..entries_count {
+            let entry_length = reader.u32(offset)? as usize;
+            ensure!(
+                entry_length >= ENTRY_HEADER_SIZE,
+                "invalid entry length at offset {offset}"
+            );
+
+            let name_offset = reader.u32(offset + 4)? as usize;
+            let vector_length = reader.u32(offset + 8)? as usize;
+            let data_type = reader.u8(offset + 12)?;
+            let units = reader.u8(offset + 14)?;
+            let data_offset = reader.u32(offset + 16)? as usize;
+
+            // Flags (offset + 13) and variability (offset + 15) are not relevant when taking snapshots
+            let name = reader.text(offset + name_offset, entry_length.saturating_sub(name_offset))?;
+            let value = match (data_type, vector_length) {
+                (b'J', 0) => Some(PerfDataValue::Long(reader.i64(offset + data_offset)?)),
+                (b'B', length) => Some(PerfDataValue::Text(reader.text(offset + data_offset, length)?)),
+                _ => None,
+            };
+
+            if let Some(value) = value {
+                let units = PerfDataUnits::try_from(units)?;
+                entries.insert(name, PerfDataEntry { units, value });
+            }
+
+            offset += entry_length;
+        }
+
+        Ok(Self { entries })
+    }
+
+    pub fn text(&self, name: &str) -> Option<&str> {
+        match &self.entries.get(name)?.value {
+            PerfDataValue::Text(text) => Some(text.as_str()),
+            PerfDataValue::Long(_) => None,
+        }
+    }
+
+    pub fn bytes(&self, name: &str) -> Option<ByteUnit> {
+        let entry = self.entries.get(name)?;
+        match (entry.units, &entry.value) {
+            (PerfDataUnits::Bytes, PerfDataValue::Long(value)) => Some(ByteUnit::from(*value.max(&0) as u64)),
+            _ => None,
+        }
+    }
+
+    pub fn ticks(&self, name: &str) -> Option<Duration> {
+        let entry = self.entries.get(name)?;
+        let frequency = self.entries.get("sun.os.hrt.frequency")?;
+
+        match (entry.units, &entry.value, frequency.units, &frequency.value) {
+            (PerfDataUnits::Ticks, PerfDataValue::Long(ticks), PerfDataUnits::Hertz, PerfDataValue::Long(hertz))
+                if *hertz > 0 =>
+            {
+                let nanos = (*ticks.max(&0) as u128) * 1_000_000_000 / (*hertz as u128);
+                Some(Duration::from_nanos(nanos as u64))
+            },
+            _ => None,
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(|name| name.as_str())
+    }
+}
+
+impl TryFrom<u8> for PerfDataUnits {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let units = match value {
+            1 => PerfDataUnits::None,
+            2 => PerfDataUnits::Bytes,
+            3 => PerfDataUnits::Ticks,
+            4 => PerfDataUnits::Events,
+            5 => PerfDataUnits::String,
+            6 => PerfDataUnits::Hertz,
+            other => bail!("unexpected units {other}"),
+        };
+
+        Ok(units)
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl Reader<'_> {
+    fn slice(&self, offset: usize, length: usize) -> anyhow::Result<&[u8]> {
+        offset
+            .checked_add(length)
+            .and_then(|end| self.bytes.get(offset..end))
+            .with_context(|| format!("truncated data at offset {offset}"))
+    }
+
+    fn u8(&self, offset: usize) -> anyhow::Result<u8> {
+        Ok(self.slice(offset, 1)?[0])
+    }
+
+    fn u32(&self, offset: usize) -> anyhow::Result<u32> {
+        let raw = self.slice(offset, 4)?.try_into()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(raw)
+        } else {
+            u32::from_be_bytes(raw)
+        })
+    }
+
+    fn i64(&self, offset: usize) -> anyhow::Result<i64> {
+        let raw = self.slice(offset, 8)?.try_into()?;
+        Ok(if self.little_endian {
+            i64::from_le_bytes(raw)
+        } else {
+            i64::from_be_bytes(raw)
+        })
+    }
+
+    // Strings are NUL-terminated inside a fixed-size vector
+    fn text(&self, offset: usize, max_length: usize) -> anyhow::Result<String> {
+        let available = max_length.min(self.bytes.len().saturating_sub(offset));
+        let raw = self.slice(offset, available)?;
+        let terminated = raw.split(|byte| *byte == 0).next().unwrap_or_default();
+        Ok(String::from_utf8_lossy(terminated).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::ram::perfdata::PerfData;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use ubyte::ByteUnit;
+
+    fn fake_hsperfdata(pid: u32) -> PathBuf {
+        let root_dir = std::env::current_dir().expect("Cannot find current dir");
+        root_dir.join("test-data").join("hsperf").join(pid.to_string())
+    }
+
+    #[test]
+    fn should_parse_counters_from_hsperfdata() {
+        let perf_data = PerfData::read(&fake_hsperfdata(45927)).expect("Cannot parse hsperfdata");
+
+        assert_eq!(
+            perf_data.text("sun.rt.javaCommand"),
+            Some("org.gradle.launcher.daemon.bootstrap.GradleDaemon 8.7")
+        );
+        assert_eq!(perf_data.text("java.property.java.version"), Some("21"));
+        assert_eq!(
+            perf_data.bytes("sun.gc.metaspace.used"),
+            Some(ByteUnit::from(300703600))
+        );
+        assert_eq!(
+            perf_data.ticks("sun.os.hrt.ticks"),
+            Some(Duration::from_nanos(8057374588750))
+        );
+    }
+
+    #[test]
+    fn should_reject_unexpected_magic_number() {
+        let mut bytes = std::fs::read(fake_hsperfdata(45927)).expect("Cannot read hsperfdata");
+        bytes[0] = 0;
+
+        assert!(PerfData::parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn should_reject_truncated_hsperfdata() {
+        let bytes = std::fs::read(fake_hsperfdata(45927)).expect("Cannot read hsperfdata");
+
+        assert!(PerfData::parse(&bytes[..1024]).is_err());
+    }
+}
@@ -0,0 +1,410 @@
+// Copyright 2025 Dotanuki Labs
+// SPDX-License-Identifier: MIT
+
+// Gradle keeps track of its daemons in <gradle-home>/daemon/<version>/registry.bin, written by its
+// DaemonRegistryContent serializer : a presence flag, the addresses daemons listen to, one DaemonInfo per
+// daemon (address index, context, token, state ordinal and last time busy) and the latest stop events.
+// https://github.com/gradle/gradle/blob/master/platforms/core-runtime/launcher/src/main/java/org/gradle/launcher/daemon/registry/DaemonRegistryContent.java
+// https://github.com/gradle/gradle/blob/master/platforms/core-runtime/launcher/src/main/java/org/gradle/launcher/daemon/registry/DaemonInfo.java
+// https://github.com/gradle/gradle/blob/master/platforms/core-runtime/launcher/src/main/java/org/gradle/launcher/daemon/context/DefaultDaemonContext.java
+
+use crate::core::decoding::Decoder;
+use crate::core::models::DaemonState;
+use anyhow::{Context, ensure};
+use log::debug;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const DAEMON_REGISTRY_FILE: &str = "registry.bin";
+
+// Daemon contexts gained fields along Gradle releases, namely the Java version and vendor daemons run with
+// and how they load native services. Each layout is tried until one decodes the whole registry.
+#[derive(Debug, Clone, Copy)]
+struct ContextLayout {
+    java_version: bool,
+    java_vendor: bool,
+    native_services_mode: bool,
+}
+
+const CONTEXT_LAYOUTS: [ContextLayout; 4] = [
+    ContextLayout {
+        java_version: false,
+        java_vendor: false,
+        native_services_mode: false,
+    },
+    ContextLayout {
+        java_version: true,
+        java_vendor: false,
+        native_services_mode: false,
+    },
+    ContextLayout {
+        java_version: true,
+        java_vendor: true,
+        native_services_mode: false,
+    },
+    ContextLayout {
+        java_version: true,
+        java_vendor: true,
+        native_services_mode: true,
+    },
+];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct RegisteredDaemon {
+    pid: u32,
+    state: DaemonState,
+    last_busy: SystemTime,
+}
+
+pub fn find_daemon_states(gradle_home: &Path) -> HashMap<u32, DaemonState> {
+    find_registered_daemons(gradle_home)
+        .into_iter()
+        .map(|daemon| (daemon.pid, daemon.state))
+        .collect::<HashMap<_, _>>()
+}
+
+// Idle daemons wait for work since the last time they were busy
+pub fn find_idle_daemons(gradle_home: &Path) -> HashMap<u32, SystemTime> {
+    find_registered_daemons(gradle_home)
+        .into_iter()
+        .filter(|daemon| daemon.state == DaemonState::Idle)
+        .map(|daemon| (daemon.pid, daemon.last_busy))
+        .collect::<HashMap<_, _>>()
+}
+
+fn find_registered_daemons(gradle_home: &Path) -> Vec<RegisteredDaemon> {
+    let Ok(registries) = fs::read_dir(gradle_home.join("daemon")) else {
+        return vec![];
+    };
+
+    registries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.join(DAEMON_REGISTRY_FILE).is_file())
+        .flat_map(|registry_dir| {
+            read_registry(&registry_dir).unwrap_or_else(|error| {
+                debug!(
+                    "Cannot read daemon registry at {} : {}",
+                    registry_dir.to_string_lossy(),
+                    error
+                );
+                vec![]
+            })
+        })
+        .collect::<Vec<_>>()
+}
+
+fn read_registry(registry_dir: &Path) -> anyhow::Result<Vec<RegisteredDaemon>> {
+    let bytes = fs::read(registry_dir.join(DAEMON_REGISTRY_FILE))?;
+    let daemons = decode_registry(&bytes)?;
+
+    let version = registry_dir.file_name().unwrap_or_default().to_string_lossy();
+    debug!("Daemons registered for Gradle {} : {:?}", version, daemons);
+    Ok(daemons)
+}
+
+fn decode_registry(bytes: &[u8]) -> anyhow::Result<Vec<RegisteredDaemon>> {
+    CONTEXT_LAYOUTS
+        .iter()
+        .find_map(|layout| decode_registry_content(&mut Decoder::new(bytes), layout).ok())
+        .context("unsupported daemon registry layout")
+}
+
+fn decode_registry_content(decoder: &mut Decoder<'_>, layout: &ContextLayout) -> anyhow::Result<Vec<RegisteredDaemon>> {
+    if !decoder.read_boolean()? {
+        return Ok(vec![]);
+    }
+
+    let addresses = decoder.read_count()?;
+    for _ in 0..addresses {
+        skip_address(decoder)?;
+    }
+
+    let daemons = (0..decoder.read_count()?)
+        .map(|_| decode_daemon_info(decoder, addresses, layout))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    for _ in 0..decoder.read_count()? {
+        skip_stop_event(decoder)?;
+    }
+
+    ensure!(decoder.is_exhausted(), "unexpected trailing data");
+    Ok(daemons.into_iter().flatten().collect())
+}
+
+// Daemons listen to a MultiChoiceAddress : an UUID, a port and the candidate IP addresses
+fn skip_address(decoder: &mut Decoder<'_>) -> anyhow::Result<()> {
+    decoder.skip(16)?;
+    decoder.read_int()?;
+
+    for _ in 0..decoder.read_count()? {
+        decoder.read_binary()?;
+    }
+
+    Ok(())
+}
+
+// Daemons started without a known PID can't be matched with running JVMs
+fn decode_daemon_info(
+    decoder: &mut Decoder<'_>,
+    addresses: usize,
+    layout: &ContextLayout,
+) -> anyhow::Result<Option<RegisteredDaemon>> {
+    let address = decoder.read_int()?;
+    ensure!(
+        usize::try_from(address).is_ok_and(|index| index < addresses),
+        "unexpected address index {address}"
+    );
+
+    let pid = decode_daemon_context(decoder, layout)?;
+    decoder.read_binary()?;
+    let state = DaemonState::try_from(decoder.read_byte()?)?;
+    let last_busy = u64::try_from(decoder.read_long()?)?;
+
+    Ok(pid.map(|pid| RegisteredDaemon {
+        pid,
+        state,
+        last_busy: UNIX_EPOCH + Duration::from_millis(last_busy),
+    }))
+}
+
+fn decode_daemon_context(decoder: &mut Decoder<'_>, layout: &ContextLayout) -> anyhow::Result<Option<u32>> {
+    decoder.read_nullable_string()?;
+    decoder.read_string()?;
+
+    if layout.java_version {
+        decoder.read_int()?;
+    }
+
+    if layout.java_vendor {
+        decoder.read_string()?;
+    }
+
+    decoder.read_string()?;
+
+    let pid = match decoder.read_boolean()? {
+        true => Some(u32::try_from(decoder.read_long()?)?),
+        false => None,
+    };
+
+    if decoder.read_boolean()? {
+        decoder.read_int()?;
+    }
+
+    for _ in 0..decoder.read_count()? {
+        decoder.read_string()?;
+    }
+
+    decoder.read_boolean()?;
+
+    if layout.native_services_mode {
+        decoder.read_int()?;
+    }
+
+    if decoder.read_boolean()? {
+        decoder.read_int()?;
+    }
+
+    Ok(pid)
+}
+
+// Stop events record when and why daemons stopped : timestamp, PID, expiration status and reason
+fn skip_stop_event(decoder: &mut Decoder<'_>) -> anyhow::Result<()> {
+    decoder.read_long()?;
+
+    if decoder.read_boolean()? {
+        decoder.read_long()?;
+    }
+
+    if decoder.read_boolean()? {
+        decoder.read_byte()?;
+    }
+
+    decoder.read_nullable_string()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::DaemonState;
+    use crate::core::ram::registry::{RegisteredDaemon, decode_registry, find_daemon_states, find_idle_daemons};
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::Path;
+    use std::time::{Duration, UNIX_EPOCH};
+    use temp_dir::TempDir;
+
+    const LAST_BUSY_MILLIS: i64 = 1_717_171_717_171;
+
+    // Mirrors what DaemonRegistryContent.Serializer writes through Gradle's OutputStreamBackedEncoder
+    #[derive(Default)]
+    struct Encoder {
+        bytes: Vec<u8>,
+    }
+
+    impl Encoder {
+        fn boolean(&mut self, value: bool) -> &mut Self {
+            self.bytes.push(value as u8);
+            self
+        }
+
+        fn int(&mut self, value: i32) -> &mut Self {
+            self.bytes.extend(value.to_be_bytes());
+            self
+        }
+
+        fn long(&mut self, value: i64) -> &mut Self {
+            self.bytes.extend(value.to_be_bytes());
+            self
+        }
+
+        fn string(&mut self, value: &str) -> &mut Self {
+            self.bytes.extend((value.len() as u16).to_be_bytes());
+            self.bytes.extend(value.as_bytes());
+            self
+        }
+
+        fn binary(&mut self, value: &[u8]) -> &mut Self {
+            self.int(value.len() as i32);
+            self.bytes.extend(value);
+            self
+        }
+    }
+
+    fn encode_registry(daemons: &[(Option<i64>, u8)], recent_layout: bool) -> Vec<u8> {
+        let mut encoder = Encoder::default();
+        encoder.boolean(true).int(daemons.len() as i32);
+
+        for (index, _) in daemons.iter().enumerate() {
+            encoder
+                .long(0x3a4c_4f0e)
+                .long(index as i64)
+                .int(54321)
+                .int(1)
+                .binary(&[127, 0, 0, 1]);
+        }
+
+        encoder.int(daemons.len() as i32);
+
+        for (index, (pid, state)) in daemons.iter().enumerate() {
+            encoder
+                .int(index as i32)
+                .boolean(true)
+                .string("c7a1e3b0-51d2-4c1c-b0a3-6f1d1e0c3b7e")
+                .string("/opt/jdks/temurin-21");
+
+            if recent_layout {
+                encoder.int(21).string("Eclipse Adoptium");
+            }
+
+            encoder.string("/Users/dev/.gradle/daemon/8.7").boolean(pid.is_some());
+            if let Some(pid) = pid {
+                encoder.long(*pid);
+            }
+
+            encoder
+                .boolean(true)
+                .int(10_800_000)
+                .int(2)
+                .string("-Xmx2g")
+                .string("-Dfile.encoding=UTF-8")
+                .boolean(true);
+
+            if recent_layout {
+                encoder.int(0);
+            }
+
+            encoder.boolean(false).binary(&[7; 16]).bytes.push(*state);
+            encoder.long(LAST_BUSY_MILLIS);
+        }
+
+        encoder
+            .int(1)
+            .long(LAST_BUSY_MILLIS)
+            .boolean(true)
+            .long(41234)
+            .boolean(true)
+            .bytes
+            .push(2);
+        encoder.boolean(true).string("stop command received");
+
+        encoder.bytes
+    }
+
+    fn write_registry(gradle_home: &Path, version: &str, bytes: &[u8]) {
+        let registry_dir = gradle_home.join("daemon").join(version);
+        fs::create_dir_all(&registry_dir).expect("Cannot create registry dir");
+        fs::write(registry_dir.join("registry.bin"), bytes).expect("Cannot write registry");
+    }
+
+    #[test]
+    fn should_decode_daemons_from_registries() {
+        let registry = encode_registry(&[(Some(45927), 1), (None, 0), (Some(50045), 0)], false);
+
+        let daemons = decode_registry(&registry).expect("Cannot decode registry");
+
+        let last_busy = UNIX_EPOCH + Duration::from_millis(LAST_BUSY_MILLIS as u64);
+        let expected = vec![
+            RegisteredDaemon {
+                pid: 45927,
+                state: DaemonState::Busy,
+                last_busy,
+            },
+            RegisteredDaemon {
+                pid: 50045,
+                state: DaemonState::Idle,
+                last_busy,
+            },
+        ];
+
+        assert_eq!(daemons, expected);
+    }
+
+    #[test]
+    fn should_find_daemon_states_from_registries_of_any_layout() {
+        let gradle_home = TempDir::new().expect("Cannot create temp dir");
+        write_registry(
+            gradle_home.path(),
+            "8.7",
+            &encode_registry(&[(Some(45927), 1), (Some(50045), 0)], false),
+        );
+        write_registry(gradle_home.path(), "8.12", &encode_registry(&[(Some(49739), 2)], true));
+
+        let states = find_daemon_states(gradle_home.path());
+
+        let expected = HashMap::from([
+            (45927, DaemonState::Busy),
+            (50045, DaemonState::Idle),
+            (49739, DaemonState::Canceled),
+        ]);
+
+        assert_eq!(states, expected);
+    }
+
+    #[test]
+    fn should_find_since_when_daemons_are_idle() {
+        let gradle_home = TempDir::new().expect("Cannot create temp dir");
+        write_registry(
+            gradle_home.path(),
+            "8.7",
+            &encode_registry(&[(Some(45927), 1), (Some(50045), 0)], false),
+        );
+
+        let idle_daemons = find_idle_daemons(gradle_home.path());
+
+        let expected = HashMap::from([(50045, UNIX_EPOCH + Duration::from_millis(LAST_BUSY_MILLIS as u64))]);
+        assert_eq!(idle_daemons, expected);
+    }
+
+    #[test]
+    fn should_ignore_unreadable_registries() {
+        let gradle_home = TempDir::new().expect("Cannot create temp dir");
+        let truncated = encode_registry(&[(Some(45927), 1)], false);
+        write_registry(gradle_home.path(), "8.7", b"not a registry");
+        write_registry(gradle_home.path(), "8.4", &truncated[..truncated.len() - 12]);
+
+        assert!(find_daemon_states(gradle_home.path()).is_empty());
+    }
+}
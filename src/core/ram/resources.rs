@@ -1,22 +1,26 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::{AllocatedResource, MemoryCached, UseCase};
+use crate::core::models::{
+    AllocatedResource, DaemonState, JvmMemoryUsage, JvmProcess, MemoryCached, ProcessStats, ProcessUsage, UseCase,
+};
 use crate::core::ram::jps::find_jvm_processes;
 use itertools::Itertools;
+use std::collections::HashMap;
 use std::path::PathBuf;
-use sysinfo::Pid;
+use std::time::Duration;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, UpdateKind};
 use ubyte::ByteUnit;
 
 pub fn find_resources_used_by_jvm(
     hsperfdata_locator: fn() -> PathBuf,
-    resources_converter: fn(u32, String) -> Option<(MemoryCached, u64)>,
+    resources_converter: fn(&JvmProcess) -> Option<(MemoryCached, u64)>,
 ) -> anyhow::Result<Vec<AllocatedResource>> {
     let jvm_processes = find_jvm_processes(hsperfdata_locator)?;
 
     let resources = jvm_processes
         .into_iter()
-        .filter_map(|(pid, class_name)| resources_converter(pid, class_name))
+        .filter_map(|process| resources_converter(&process))
         .map(|(cached, memory)| (UseCase::from(cached), memory))
         .sorted_by_key(|item| item.0)
         .chunk_by(|item| item.0)
@@ -33,31 +37,128 @@ pub fn find_resources_used_by_jvm(
     Ok(resources)
 }
 
-pub fn convert_to_allocated_resources(pid: u32, launcher_class_name: String) -> Option<(MemoryCached, u64)> {
+pub fn find_jvm_memory_usage(hsperfdata_locator: fn() -> PathBuf) -> anyhow::Result<Vec<JvmMemoryUsage>> {
+    let jvm_processes = find_jvm_processes(hsperfdata_locator)?;
+
+    let usages = jvm_processes
+        .into_iter()
+        .map(|process| {
+            (
+                UseCase::from(memory_type_from_jvm_launcher_class(process.launcher())),
+                process,
+            )
+        })
+        .sorted_by_key(|item| item.0)
+        .chunk_by(|item| item.0)
+        .into_iter()
+        .map(|(use_case, group)| {
+            let zero = ByteUnit::from(0);
+            group.fold(
+                JvmMemoryUsage::new(use_case, zero, zero, zero, zero),
+                |usage, (_, process)| JvmMemoryUsage {
+                    heap_committed: usage.heap_committed + process.heap_capacity,
+                    heap_used: usage.heap_used + process.heap_used,
+                    metaspace_committed: usage.metaspace_committed + process.metaspace_capacity,
+                    metaspace_used: usage.metaspace_used + process.metaspace_used,
+                    ..usage
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Ok(usages)
+}
+
+pub fn find_processes_used_by_jvm(
+    hsperfdata_locator: fn() -> PathBuf,
+    daemon_states: &HashMap<u32, DaemonState>,
+    stats_reader: fn(u32) -> Option<ProcessStats>,
+) -> anyhow::Result<Vec<ProcessUsage>> {
+    let jvm_processes = find_jvm_processes(hsperfdata_locator)?;
+
+    // Processes that are gone by now (stale hsperfdata files) are not listed
+    let processes = jvm_processes
+        .into_iter()
+        .filter_map(|process| {
+            let stats = stats_reader(process.pid)?;
+            let usage = ProcessUsage {
+                pid: process.pid,
+                use_case: UseCase::from(memory_type_from_jvm_launcher_class(process.launcher())),
+                tool_version: process.tool_version().map(String::from),
+                java_version: process.java_version.clone(),
+                daemon_state: daemon_states.get(&process.pid).copied(),
+                stats,
+            };
+            Some(usage)
+        })
+        .sorted_by_key(|usage| std::cmp::Reverse(usage.stats.rss))
+        .collect::<Vec<_>>();
+
+    Ok(processes)
+}
+
+pub fn read_process_stats(pid: u32) -> Option<ProcessStats> {
+    let pid = Pid::from_u32(pid);
+    let refresh_kind = ProcessRefreshKind::nothing()
+        .with_memory()
+        .with_cwd(UpdateKind::OnlyIfNotSet);
+
+    let mut system = sysinfo::System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, refresh_kind);
+
+    system.process(pid).map(|process| ProcessStats {
+        rss: ByteUnit::from(process.memory()),
+        uptime: Duration::from_secs(process.run_time()),
+        working_dir: process.cwd().map(PathBuf::from),
+    })
+}
+
+pub fn convert_to_allocated_resources(jvm_process: &JvmProcess) -> Option<(MemoryCached, u64)> {
     let mut system = sysinfo::System::new_all();
     system.refresh_all();
 
-    system.process(Pid::from_u32(pid)).map(|process| {
+    system.process(Pid::from_u32(jvm_process.pid)).map(|process| {
         (
-            memory_type_from_jvm_launcher_class(&launcher_class_name),
+            memory_type_from_jvm_launcher_class(jvm_process.launcher()),
             process.memory(),
         )
     })
 }
 
+const LANGUAGE_SERVER_MARKERS: [&str; 3] = ["lspserver", "languageserver", "kotlinlsp"];
+
+// Rules are evaluated in order, since an IDE or a language server may well mention Kotlin or Gradle
 pub fn memory_type_from_jvm_launcher_class(launcher_class_name: &str) -> MemoryCached {
-    match launcher_class_name {
-        _ if launcher_class_name.to_lowercase().contains("kotlin") => MemoryCached::KotlinCompilerDaemon,
-        _ if launcher_class_name.to_lowercase().contains("gradle") => MemoryCached::GradleBuildDaemon,
+    let launcher = launcher_class_name.to_lowercase();
+
+    match launcher {
+        _ if launcher.starts_with("com.intellij.") => MemoryCached::IntegratedDevelopmentEnvironment,
+        _ if LANGUAGE_SERVER_MARKERS.iter().any(|marker| launcher.contains(marker))
+            || launcher.starts_with("org.javacs.") =>
+        {
+            MemoryCached::LanguageServer
+        },
+        _ if launcher.contains("gradleworkermain") => MemoryCached::GradleWorkerDaemon,
+        _ if launcher.contains("kotlin") => MemoryCached::KotlinCompilerDaemon,
+        _ if launcher.contains("gradle") => MemoryCached::GradleBuildDaemon,
+        _ if launcher.contains("mvndaemon") => MemoryCached::MavenDaemon,
+        _ if launcher.ends_with("a-server.jar") => MemoryCached::BazelServer,
         _ => MemoryCached::OtherJavaProcess,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::core::models::{AllocatedResource, MemoryCached, UseCase};
-    use crate::core::ram::find_resources_used_by_jvm;
+    use crate::core::models::{
+        AllocatedResource, DaemonState, JvmMemoryUsage, JvmProcess, MemoryCached, ProcessStats, ProcessUsage, UseCase,
+    };
+    use crate::core::ram::{
+        find_jvm_memory_usage, find_processes_used_by_jvm, find_resources_used_by_jvm,
+        memory_type_from_jvm_launcher_class,
+    };
+    use std::collections::HashMap;
     use std::path::PathBuf;
+    use std::time::Duration;
     use ubyte::{ByteUnit, ToByteUnit};
 
     fn locate_fake_hsperdata() -> PathBuf {
@@ -65,8 +166,9 @@ mod tests {
         root_dir.join("test-data").join("hsperf")
     }
 
-    fn fake_resources_converter(_: u32, launcher_name: String) -> Option<(MemoryCached, u64)> {
-        let converted = match &launcher_name {
+    fn fake_resources_converter(process: &JvmProcess) -> Option<(MemoryCached, u64)> {
+        let launcher_name = process.launcher();
+        let converted = match launcher_name {
             _ if launcher_name.to_lowercase().contains("kotlin") => {
                 (MemoryCached::KotlinCompilerDaemon, 1.gibibytes().as_u64())
             },
@@ -97,4 +199,105 @@ mod tests {
 
         assert_eq!(resources, expected)
     }
+
+    #[test]
+    fn should_evaluate_jvm_memory_usage() {
+        let usages = find_jvm_memory_usage(locate_fake_hsperdata).expect("Cannot evaluate JVM memory");
+
+        let expected = vec![
+            (
+                MemoryCached::GradleBuildDaemon,
+                [5792858112u64, 1906044048, 613548032, 603919016],
+            ),
+            (
+                MemoryCached::KotlinCompilerDaemon,
+                [2343567360u64, 394571800, 203227136, 201178888],
+            ),
+            (
+                MemoryCached::IntegratedDevelopmentEnvironment,
+                [3198156832u64, 2264821232, 422772736, 419634848],
+            ),
+        ]
+        .into_iter()
+        .map(
+            |(cached, [heap_committed, heap_used, metaspace_committed, metaspace_used])| {
+                JvmMemoryUsage::new(
+                    UseCase::from(cached),
+                    ByteUnit::from(heap_committed),
+                    ByteUnit::from(heap_used),
+                    ByteUnit::from(metaspace_committed),
+                    ByteUnit::from(metaspace_used),
+                )
+            },
+        )
+        .collect::<Vec<_>>();
+
+        assert_eq!(usages, expected)
+    }
+
+    // Only Gradle daemons are still alive, each one taking pid x 1KiB
+    fn fake_stats_reader(pid: u32) -> Option<ProcessStats> {
+        [45927, 49739, 50045].contains(&pid).then(|| ProcessStats {
+            rss: pid.kibibytes(),
+            uptime: Duration::from_secs(60),
+            working_dir: Some(PathBuf::from("/Users/lonewolf/Projects")),
+        })
+    }
+
+    #[test]
+    fn should_list_running_jvm_processes() {
+        let daemon_states = HashMap::from([(45927, DaemonState::Busy), (49739, DaemonState::Idle)]);
+        let processes = find_processes_used_by_jvm(locate_fake_hsperdata, &daemon_states, fake_stats_reader)
+            .expect("Cannot list processes");
+
+        let expected = [(50045, "8.2"), (49739, "8.4"), (45927, "8.7")]
+            .into_iter()
+            .map(|(pid, version)| ProcessUsage {
+                pid,
+                use_case: UseCase::from(MemoryCached::GradleBuildDaemon),
+                tool_version: Some(String::from(version)),
+                java_version: Some(String::from("21")),
+                daemon_state: daemon_states.get(&pid).copied(),
+                stats: fake_stats_reader(pid).expect("Missing fake stats"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(processes, expected)
+    }
+
+    #[test]
+    fn should_classify_jvm_processes_from_launcher() {
+        let launchers = [
+            "org.gradle.launcher.daemon.bootstrap.GradleDaemon",
+            "worker.org.gradle.process.internal.worker.GradleWorkerMain",
+            "org.jetbrains.kotlin.daemon.KotlinCompileDaemon",
+            "org.mvndaemon.mvnd.common.MavenDaemon",
+            "/var/tmp/_bazel_dev/install/3f5b8d6a/A-server.jar",
+            "com.jetbrains.ls.kotlinLsp.KotlinLspServerKt",
+            "org.javacs.kt.MainKt",
+            "com.intellij.idea.Main",
+            "org.apache.catalina.startup.Bootstrap",
+            "org.example.ToolsPlugin",
+        ];
+
+        let classified = launchers
+            .into_iter()
+            .map(memory_type_from_jvm_launcher_class)
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            MemoryCached::GradleBuildDaemon,
+            MemoryCached::GradleWorkerDaemon,
+            MemoryCached::KotlinCompilerDaemon,
+            MemoryCached::MavenDaemon,
+            MemoryCached::BazelServer,
+            MemoryCached::LanguageServer,
+            MemoryCached::LanguageServer,
+            MemoryCached::IntegratedDevelopmentEnvironment,
+            MemoryCached::OtherJavaProcess,
+            MemoryCached::OtherJavaProcess,
+        ];
+
+        assert_eq!(classified, expected)
+    }
 }
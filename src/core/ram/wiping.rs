@@ -1,29 +1,644 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::MemoryCached;
+use crate::core::models::{
+    DaemonState, JvmProcess, MemoryCached, ProcessActivity, ProcessFilters, ProcessProtection, ProcessShutdown,
+    ProtectedProcess, StoppedProcess,
+};
 use crate::core::ram::jps::find_jvm_processes;
 use crate::core::ram::memory_type_from_jvm_launcher_class;
+use anyhow::bail;
 use log::debug;
+use std::collections::HashMap;
 use std::path::PathBuf;
-use sysinfo::Pid;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal, System};
 
-pub fn cleanup_memory(hsperfdata_locator: fn() -> PathBuf, caches: &[MemoryCached]) {
+const PROCESS_POLLING_INTERVAL: Duration = Duration::from_millis(250);
+
+pub fn find_processes_to_cleanup(
+    hsperfdata_locator: fn() -> PathBuf,
+    caches: &[MemoryCached],
+    filters: &ProcessFilters,
+    daemon_states: &HashMap<u32, DaemonState>,
+    idle_daemons: &HashMap<u32, SystemTime>,
+) -> Vec<JvmProcess> {
+    let candidates = find_jvm_processes(hsperfdata_locator)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|process| JvmProcess {
+            daemon_state: daemon_states.get(&process.pid).copied(),
+            ..process
+        })
+        .filter(|process| caches.contains(&memory_type_from_jvm_launcher_class(process.launcher())))
+        .filter(|process| matches_gradle_version(process, filters))
+        .collect::<Vec<_>>();
+
+    if !filters.needs_activity() {
+        return candidates;
+    }
+
+    let activities = find_activities(&candidates, idle_daemons);
+    let now = SystemTime::now();
+
+    candidates
+        .into_iter()
+        .filter(|process| matches_activity(activities.get(&process.pid), filters, now))
+        .collect::<Vec<_>>()
+}
+
+pub fn cleanup_memory(
+    processes: Vec<JvmProcess>,
+    protection: &ProcessProtection,
+    force: bool,
+    grace_period: Duration,
+) -> (Vec<StoppedProcess>, Vec<ProtectedProcess>, Vec<String>) {
     debug!("");
     debug!("Cleaning up JVM processes");
     debug!("");
 
-    let jvm_processes = find_jvm_processes(hsperfdata_locator).unwrap_or_default();
-    let mut system = sysinfo::System::new_all();
-    system.refresh_all();
-
-    jvm_processes.into_iter().for_each(|(pid, launcher_class_name)| {
-        let cache_type = memory_type_from_jvm_launcher_class(&launcher_class_name);
-        if caches.contains(&cache_type)
-            && let Some(process) = system.process(Pid::from_u32(pid))
-            && process.kill()
-        {
-            debug!("Killed : {} ({})", &launcher_class_name, pid);
+    let (processes, protected) = separate_protected_processes(processes, protection, force, read_process_name);
+    let (stopped, failures) = stop_processes(processes, grace_period);
+    (stopped, protected, failures)
+}
+
+pub fn separate_protected_processes(
+    processes: Vec<JvmProcess>,
+    protection: &ProcessProtection,
+    force: bool,
+    name_reader: fn(u32) -> Option<String>,
+) -> (Vec<JvmProcess>, Vec<ProtectedProcess>) {
+    let mut unprotected = Vec::new();
+    let mut protected = Vec::new();
+
+    for process in processes {
+        match protection_reason(&process, protection, force, name_reader) {
+            Some(reason) => {
+                debug!("Protected : {} ({}) : {}", process.launcher(), process.pid, reason);
+                protected.push(ProtectedProcess::new(
+                    process.pid,
+                    process.launcher().to_string(),
+                    reason,
+                ));
+            },
+            None => unprotected.push(process),
+        }
+    }
+
+    (unprotected, protected)
+}
+
+// Stopping a Gradle worker breaks the build of the daemon that spawned it, hence workers share its protection
+pub fn separate_workers_of_protected_daemons(
+    processes: Vec<JvmProcess>,
+    protected_daemons: &[ProtectedProcess],
+    parent_reader: fn(u32) -> Option<u32>,
+) -> (Vec<JvmProcess>, Vec<ProtectedProcess>) {
+    let mut unprotected = Vec::new();
+    let mut protected = Vec::new();
+
+    for process in processes {
+        let is_worker = memory_type_from_jvm_launcher_class(process.launcher()) == MemoryCached::GradleWorkerDaemon;
+        let parent = is_worker.then(|| parent_reader(process.pid)).flatten();
+
+        match parent.filter(|parent| protected_daemons.iter().any(|daemon| daemon.pid == *parent)) {
+            Some(parent) => {
+                debug!(
+                    "Protected : {} ({}) : spawned by {}",
+                    process.launcher(),
+                    process.pid,
+                    parent
+                );
+                protected.push(ProtectedProcess::new(
+                    process.pid,
+                    process.launcher().to_string(),
+                    format!("spawned by protected Gradle daemon {parent}"),
+                ));
+            },
+            None => unprotected.push(process),
+        }
+    }
+
+    (unprotected, protected)
+}
+
+fn protection_reason(
+    process: &JvmProcess,
+    protection: &ProcessProtection,
+    force: bool,
+    name_reader: fn(u32) -> Option<String>,
+) -> Option<String> {
+    if !force && process.daemon_state == Some(DaemonState::Busy) {
+        return Some(String::from("busy running a build, use --force to stop it anyway"));
+    }
+
+    if protection.pids.contains(&process.pid) {
+        return Some(String::from("protected PID"));
+    }
+
+    if let Some(pattern) = protection
+        .launchers
+        .iter()
+        .find(|pattern| pattern.matches(process.launcher()))
+    {
+        return Some(format!("launcher matches {}", pattern.as_str()));
+    }
+
+    if protection.names.is_empty() {
+        return None;
+    }
+
+    let name = name_reader(process.pid)?;
+    protection
+        .names
+        .iter()
+        .any(|protected| protected.eq_ignore_ascii_case(&name))
+        .then(|| format!("process named {name}"))
+}
+
+pub fn read_process_name(pid: u32) -> Option<String> {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, ProcessRefreshKind::nothing());
+
+    system
+        .process(pid)
+        .map(|process| process.name().to_string_lossy().to_string())
+}
+
+// Returns processes running builds and Gradle processes whose state can't be told, like daemons missing from
+// the registries (or whose registries can't be read)
+pub fn find_build_processes(
+    hsperfdata_locator: fn() -> PathBuf,
+    daemon_states: &HashMap<u32, DaemonState>,
+) -> (Vec<JvmProcess>, Vec<JvmProcess>) {
+    let processes = find_jvm_processes(hsperfdata_locator).unwrap_or_default();
+    separate_build_processes(processes, daemon_states, read_parent_pid)
+}
+
+// Gradle workers stay alive between builds, hence they take the state of the daemon that spawned them
+fn separate_build_processes(
+    processes: Vec<JvmProcess>,
+    daemon_states: &HashMap<u32, DaemonState>,
+    parent_reader: fn(u32) -> Option<u32>,
+) -> (Vec<JvmProcess>, Vec<JvmProcess>) {
+    let (unknown, known): (Vec<_>, Vec<_>) = processes
+        .into_iter()
+        .filter_map(|process| {
+            let daemon_state = match memory_type_from_jvm_launcher_class(process.launcher()) {
+                MemoryCached::GradleBuildDaemon => daemon_states.get(&process.pid).copied(),
+                MemoryCached::GradleWorkerDaemon => {
+                    parent_reader(process.pid).and_then(|parent| daemon_states.get(&parent).copied())
+                },
+                _ => return None,
+            };
+
+            Some(JvmProcess {
+                daemon_state,
+                ..process
+            })
+        })
+        .partition(|process| process.daemon_state.is_none());
+
+    let running = known
+        .into_iter()
+        .filter(|process| process.daemon_state == Some(DaemonState::Busy))
+        .collect::<Vec<_>>();
+
+    (running, unknown)
+}
+
+pub fn read_parent_pid(pid: u32) -> Option<u32> {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, ProcessRefreshKind::nothing());
+
+    system.process(pid)?.parent().map(|parent| parent.as_u32())
+}
+
+pub fn is_process_running(pid: u32) -> bool {
+    is_running(&mut System::new(), pid)
+}
+
+// Only Gradle daemons report the version they run, so other processes never match a requested version
+fn matches_gradle_version(process: &JvmProcess, filters: &ProcessFilters) -> bool {
+    let Some(requested) = &filters.gradle_version else {
+        return true;
+    };
+
+    match memory_type_from_jvm_launcher_class(process.launcher()) {
+        MemoryCached::GradleBuildDaemon => process
+            .tool_version()
+            .is_some_and(|version| version == requested || version.starts_with(&format!("{requested}."))),
+        _ => false,
+    }
+}
+
+// Only Gradle daemons record when they were last busy, hence idleness can't be told for other processes
+pub fn ensure_idleness_measurable(categories: &[MemoryCached], filters: &ProcessFilters) -> anyhow::Result<()> {
+    let unmeasurable = categories
+        .iter()
+        .filter(|category| **category != MemoryCached::GradleBuildDaemon)
+        .map(MemoryCached::identifier)
+        .collect::<Vec<_>>();
+
+    if filters.idle_for.is_none() || unmeasurable.is_empty() {
+        return Ok(());
+    }
+
+    bail!(
+        "Cannot tell how long {} processes have been idle, since only Gradle daemons record when they were last busy. \
+        Pass --only gradle-daemon along with --idle-for",
+        unmeasurable.join(", ")
+    )
+}
+
+// Only Gradle daemons record when they were last busy, so other processes never match a requested idleness
+fn matches_activity(activity: Option<&ProcessActivity>, filters: &ProcessFilters, now: SystemTime) -> bool {
+    let Some(activity) = activity else {
+        return false;
+    };
+
+    let old_enough = filters.older_than.is_none_or(|age| activity.uptime >= age);
+
+    let idle_enough = filters.idle_for.is_none_or(|idle| {
+        activity
+            .idle_since
+            .and_then(|since| now.duration_since(since).ok())
+            .is_some_and(|idle_time| idle_time >= idle)
+    });
+
+    old_enough && idle_enough
+}
+
+fn find_activities(processes: &[JvmProcess], idle_daemons: &HashMap<u32, SystemTime>) -> HashMap<u32, ProcessActivity> {
+    let pids = processes
+        .iter()
+        .map(|process| Pid::from_u32(process.pid))
+        .collect::<Vec<_>>();
+
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&pids), true, ProcessRefreshKind::nothing());
+
+    pids.into_iter()
+        .filter_map(|pid| {
+            let process = system.process(pid)?;
+            let activity = ProcessActivity {
+                uptime: Duration::from_secs(process.run_time()),
+                idle_since: idle_daemons.get(&pid.as_u32()).copied(),
+            };
+            debug!("Activity for {} : {:?}", pid, activity);
+            Some((pid.as_u32(), activity))
+        })
+        .collect::<HashMap<_, _>>()
+}
+
+// Asks processes to terminate (SIGTERM), so Gradle daemons can release locks and flush caches,
+// and only kills (SIGKILL) the ones still alive once the grace period is over
+fn stop_processes(processes: Vec<JvmProcess>, grace_period: Duration) -> (Vec<StoppedProcess>, Vec<String>) {
+    let mut system = System::new();
+    let pids = processes
+        .iter()
+        .map(|process| Pid::from_u32(process.pid))
+        .collect::<Vec<_>>();
+    system.refresh_processes(ProcessesToUpdate::Some(&pids), true);
+
+    let terminating = processes
+        .into_iter()
+        .filter_map(|jvm_process| {
+            let process = system.process(Pid::from_u32(jvm_process.pid))?;
+            let terminated = process.kill_with(Signal::Term).unwrap_or(false);
+            Some((jvm_process.pid, jvm_process.launcher().to_string(), terminated))
+        })
+        .collect::<Vec<_>>();
+
+    let deadline = Instant::now() + grace_period;
+    while Instant::now() < deadline && terminating.iter().any(|(pid, _, _)| is_running(&mut system, *pid)) {
+        thread::sleep(PROCESS_POLLING_INTERVAL);
+    }
+
+    let mut stopped = Vec::new();
+    let mut failures = Vec::new();
+
+    for (pid, launcher, terminated) in terminating {
+        if terminated && !is_running(&mut system, pid) {
+            debug!("Stopped gracefully : {} ({})", &launcher, pid);
+            stopped.push(StoppedProcess::new(pid, launcher, ProcessShutdown::Graceful));
+            continue;
+        }
+
+        match system.process(Pid::from_u32(pid)).map(|process| process.kill()) {
+            Some(true) => {
+                debug!("Killed : {} ({})", &launcher, pid);
+                stopped.push(StoppedProcess::new(pid, launcher, ProcessShutdown::Forced));
+            },
+            _ => failures.push(format!("{launcher} ({pid}) : cannot be killed")),
+        }
+    }
+
+    (stopped, failures)
+}
+
+fn is_running(system: &mut System, pid: u32) -> bool {
+    let pid = Pid::from_u32(pid);
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+
+    system
+        .process(pid)
+        .is_some_and(|process| process.status() != ProcessStatus::Zombie)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::{
+        DaemonState, JvmProcess, MemoryCached, ProcessActivity, ProcessFilters, ProcessProtection, ProcessShutdown,
+        ProtectedProcess, StoppedProcess,
+    };
+    use crate::core::ram::wiping::{
+        ensure_idleness_measurable, matches_activity, separate_build_processes, stop_processes,
+    };
+    use crate::core::ram::{
+        find_build_processes, find_processes_to_cleanup, separate_protected_processes,
+        separate_workers_of_protected_daemons,
+    };
+    use glob::Pattern;
+    use itertools::Itertools;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::process::Command;
+    use std::time::{Duration, SystemTime};
+
+    fn locate_fake_hsperdata() -> PathBuf {
+        let root_dir = std::env::current_dir().expect("Cannot find current dir");
+        root_dir.join("test-data").join("hsperf")
+    }
+
+    #[test]
+    fn should_select_only_build_daemons_for_cleanup() {
+        let caches = [MemoryCached::GradleBuildDaemon, MemoryCached::KotlinCompilerDaemon];
+
+        let selected = find_processes_to_cleanup(
+            locate_fake_hsperdata,
+            &caches,
+            &ProcessFilters::default(),
+            &HashMap::new(),
+            &HashMap::new(),
+        )
+        .into_iter()
+        .map(|process| process.pid)
+        .sorted()
+        .collect::<Vec<_>>();
+
+        assert_eq!(selected, vec![45927, 49362, 49739, 50045, 50050]);
+    }
+
+    #[test]
+    fn should_select_only_gradle_daemons_matching_version() {
+        let caches = [MemoryCached::GradleBuildDaemon, MemoryCached::KotlinCompilerDaemon];
+        let filters = ProcessFilters {
+            gradle_version: Some(String::from("8.4")),
+            ..ProcessFilters::default()
+        };
+
+        let selected = find_processes_to_cleanup(
+            locate_fake_hsperdata,
+            &caches,
+            &filters,
+            &HashMap::new(),
+            &HashMap::new(),
+        )
+        .into_iter()
+        .map(|process| process.pid)
+        .collect::<Vec<_>>();
+
+        assert_eq!(selected, vec![49739]);
+    }
+
+    #[test]
+    fn should_select_only_idle_and_old_enough_processes() {
+        let filters = ProcessFilters {
+            idle_for: Some(Duration::from_secs(600)),
+            older_than: Some(Duration::from_secs(3600)),
+            ..ProcessFilters::default()
+        };
+
+        let now = SystemTime::now();
+        let activity = |minutes_up: u64, minutes_idle: Option<u64>| ProcessActivity {
+            uptime: Duration::from_secs(minutes_up * 60),
+            idle_since: minutes_idle.map(|minutes| now - Duration::from_secs(minutes * 60)),
+        };
+
+        assert!(matches_activity(Some(&activity(120, Some(30))), &filters, now));
+        assert!(!matches_activity(Some(&activity(120, Some(1))), &filters, now));
+        assert!(!matches_activity(Some(&activity(120, None)), &filters, now));
+        assert!(!matches_activity(Some(&activity(30, Some(30))), &filters, now));
+        assert!(!matches_activity(None, &filters, now));
+    }
+
+    #[test]
+    fn should_reject_idleness_filter_for_processes_not_recording_it() {
+        let filters = ProcessFilters {
+            idle_for: Some(Duration::from_secs(600)),
+            ..ProcessFilters::default()
+        };
+
+        let gradle_daemons = [MemoryCached::GradleBuildDaemon];
+        let with_kotlin_daemons = [MemoryCached::GradleBuildDaemon, MemoryCached::KotlinCompilerDaemon];
+
+        assert!(ensure_idleness_measurable(&gradle_daemons, &filters).is_ok());
+        assert!(ensure_idleness_measurable(&with_kotlin_daemons, &filters).is_err());
+        assert!(ensure_idleness_measurable(&with_kotlin_daemons, &ProcessFilters::default()).is_ok());
+    }
+
+    #[test]
+    fn should_find_gradle_daemons_running_builds() {
+        let daemon_states = HashMap::from([(45927, DaemonState::Idle), (49739, DaemonState::Busy)]);
+
+        let (running, unknown) = find_build_processes(locate_fake_hsperdata, &daemon_states);
+        let pids = |processes: Vec<JvmProcess>| processes.into_iter().map(|process| process.pid).collect::<Vec<_>>();
+
+        assert_eq!(pids(running), vec![49739]);
+        assert_eq!(pids(unknown), vec![50045]);
+    }
+
+    fn fake_parent_reader(pid: u32) -> Option<u32> {
+        match pid {
+            2001 => Some(1001),
+            2002 => Some(1002),
+            _ => None,
         }
-    })
+    }
+
+    #[test]
+    fn should_tie_gradle_workers_to_the_state_of_their_daemon() {
+        let process = |pid: u32, java_command: &str| JvmProcess {
+            pid,
+            java_command: String::from(java_command),
+            ..JvmProcess::default()
+        };
+
+        let processes = vec![
+            process(1001, "org.gradle.launcher.daemon.bootstrap.GradleDaemon 8.7"),
+            process(1002, "org.gradle.launcher.daemon.bootstrap.GradleDaemon 8.7"),
+            process(2001, "worker.org.gradle.process.internal.worker.GradleWorkerMain"),
+            process(2002, "worker.org.gradle.process.internal.worker.GradleWorkerMain"),
+            process(2003, "worker.org.gradle.process.internal.worker.GradleWorkerMain"),
+        ];
+
+        let daemon_states = HashMap::from([(1001, DaemonState::Busy), (1002, DaemonState::Idle)]);
+
+        let (running, unknown) = separate_build_processes(processes, &daemon_states, fake_parent_reader);
+        let pids = |processes: Vec<JvmProcess>| processes.into_iter().map(|process| process.pid).collect::<Vec<_>>();
+
+        assert_eq!(pids(running), vec![1001, 2001]);
+        assert_eq!(pids(unknown), vec![2003]);
+    }
+
+    #[test]
+    fn should_stop_processes_gracefully_when_they_honor_termination() {
+        let mut child = Command::new("sleep").arg("30").spawn().expect("Cannot spawn process");
+        let processes = vec![JvmProcess {
+            pid: child.id(),
+            java_command: String::from("sleep 30"),
+            ..JvmProcess::default()
+        }];
+
+        let (stopped, failures) = stop_processes(processes, Duration::from_secs(5));
+        child.wait().expect("Cannot wait for process");
+
+        let expected = vec![StoppedProcess::new(
+            child.id(),
+            String::from("sleep"),
+            ProcessShutdown::Graceful,
+        )];
+        assert_eq!(stopped, expected);
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn should_kill_processes_ignoring_termination_after_grace_period() {
+        let mut child = Command::new("sh")
+            .args(["-c", "trap '' TERM; while true; do sleep 0.1; done"])
+            .spawn()
+            .expect("Cannot spawn process");
+
+        // Gives the shell some time to install its signal handler
+        std::thread::sleep(Duration::from_millis(200));
+        let processes = vec![JvmProcess {
+            pid: child.id(),
+            java_command: String::from("sh"),
+            ..JvmProcess::default()
+        }];
+
+        let (stopped, failures) = stop_processes(processes, Duration::from_millis(500));
+        child.wait().expect("Cannot wait for process");
+
+        let expected = vec![StoppedProcess::new(
+            child.id(),
+            String::from("sh"),
+            ProcessShutdown::Forced,
+        )];
+        assert_eq!(stopped, expected);
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn should_spare_workers_of_protected_daemons() {
+        let process = |pid: u32, java_command: &str| JvmProcess {
+            pid,
+            java_command: String::from(java_command),
+            ..JvmProcess::default()
+        };
+
+        let processes = vec![
+            process(2001, "worker.org.gradle.process.internal.worker.GradleWorkerMain"),
+            process(2002, "worker.org.gradle.process.internal.worker.GradleWorkerMain"),
+            process(2003, "worker.org.gradle.process.internal.worker.GradleWorkerMain"),
+            process(3001, "org.jetbrains.kotlin.daemon.KotlinCompileDaemon"),
+        ];
+
+        let protected_daemons = vec![ProtectedProcess::new(
+            1001,
+            String::from("org.gradle.launcher.daemon.bootstrap.GradleDaemon"),
+            String::from("busy running a build, use --force to stop it anyway"),
+        )];
+
+        let (unprotected, protected) =
+            separate_workers_of_protected_daemons(processes.clone(), &protected_daemons, fake_parent_reader);
+
+        let expected = vec![ProtectedProcess::new(
+            2001,
+            String::from("worker.org.gradle.process.internal.worker.GradleWorkerMain"),
+            String::from("spawned by protected Gradle daemon 1001"),
+        )];
+
+        assert_eq!(unprotected, processes[1..].to_vec());
+        assert_eq!(protected, expected);
+    }
+
+    fn fake_name_reader(pid: u32) -> Option<String> {
+        let name = if pid == 4242 { "kafka" } else { "java" };
+        Some(String::from(name))
+    }
+
+    #[test]
+    fn should_spare_protected_processes() {
+        let process = |pid: u32, java_command: &str, daemon_state: Option<DaemonState>| JvmProcess {
+            pid,
+            java_command: String::from(java_command),
+            daemon_state,
+            ..JvmProcess::default()
+        };
+
+        let processes = vec![
+            process(
+                1001,
+                "org.gradle.launcher.daemon.bootstrap.GradleDaemon 8.7",
+                Some(DaemonState::Idle),
+            ),
+            process(
+                1002,
+                "org.gradle.launcher.daemon.bootstrap.GradleDaemon 8.7",
+                Some(DaemonState::Busy),
+            ),
+            process(1003, "com.intellij.idea.Main", None),
+            process(1004, "org.apache.catalina.startup.Bootstrap", None),
+            process(4242, "kafka.Kafka", None),
+        ];
+
+        let protection = ProcessProtection {
+            launchers: vec![Pattern::new("com.intellij.*").expect("Invalid glob")],
+            pids: vec![1004],
+            names: vec![String::from("Kafka")],
+        };
+
+        let (unprotected, protected) =
+            separate_protected_processes(processes.clone(), &protection, false, fake_name_reader);
+
+        let expected = vec![
+            ProtectedProcess::new(
+                1002,
+                String::from("org.gradle.launcher.daemon.bootstrap.GradleDaemon"),
+                String::from("busy running a build, use --force to stop it anyway"),
+            ),
+            ProtectedProcess::new(
+                1003,
+                String::from("com.intellij.idea.Main"),
+                String::from("launcher matches com.intellij.*"),
+            ),
+            ProtectedProcess::new(
+                1004,
+                String::from("org.apache.catalina.startup.Bootstrap"),
+                String::from("protected PID"),
+            ),
+            ProtectedProcess::new(4242, String::from("kafka.Kafka"), String::from("process named kafka")),
+        ];
+
+        assert_eq!(unprotected, vec![processes[0].clone()]);
+        assert_eq!(protected, expected);
+
+        let (unprotected, _) = separate_protected_processes(processes.clone(), &protection, true, fake_name_reader);
+        assert_eq!(unprotected, processes[0..2].to_vec());
+    }
 }
@@ -1,73 +1,369 @@
 // Copyright 2025 Dotanuki Labs
 // SPDX-License-Identifier: MIT
 
-use crate::core::models::MachineResource::{DiskSpace, RamMemory};
+use crate::core::models::MachineResource::{AllResources, DiskSpace, RamMemory};
 use crate::core::models::{
-    AllocatedResource, DiskCached, EvaluationOutcome, ExecutionOutcome, MachineResource, MemoryCached,
-    ProjectLevelDiskCache, UserLevelDiskCache, WipeAction, WipingOutcome,
+    AllocatedResource, CacheEntry, CategoryFilter, CombinedOutcome, DaemonState, DiskCached, DryRunOutcome,
+    EvaluationOutcome, ExecutionOptions, ExecutionOutcome, JvmProcess, MachineResource, MemoryCached, PathRemoval,
+    ProcessFilters, ProjectDiscovery, ProjectLevelDiskCache, ProtectedProcess, PrunedResource, PruningOutcome,
+    ReclaimableResource, RestoringOutcome, UseCase, UserLevelDiskCache, WipeAction, WipeTarget, WipingOutcome,
 };
 use crate::core::ram::find_resources_used_by_jvm;
 use crate::core::{disk, ram};
-use WipeAction::{DeepWipe, Evaluate, ShallowWipe};
-use log::debug;
-use std::path::PathBuf;
+use WipeAction::{
+    BudgetWipe, DeepWipe, Evaluate, PruneDistributions, PruneToolchains, PurgeQuarantines, RestoreQuarantine,
+    SelectiveWipe, ShallowWipe,
+};
+use anyhow::{Context, bail};
+use itertools::Itertools;
+use log::{debug, info, warn};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 use ubyte::ByteUnit;
 
-pub fn execute(target: &MachineResource, action: WipeAction) -> anyhow::Result<ExecutionOutcome> {
+const RUNNING_BUILDS_POLLING_INTERVAL: Duration = Duration::from_secs(2);
+
+pub fn execute(
+    target: &MachineResource,
+    action: WipeAction,
+    options: &ExecutionOptions,
+) -> anyhow::Result<ExecutionOutcome> {
     debug!("");
     debug!("Machine resource : {target}");
     debug!("Requested operation : {action}");
 
+    let user_home = disk::user_home_locator();
+    let gradle_home = disk::find_gradle_home(user_home.as_path(), options.gradle_user_home.as_deref());
+
+    // Daemons would otherwise keep stale in-memory state pointing at deleted files
+    if matches!(target, DiskSpace) && !matches!(action, Evaluate) && options.stop_daemons {
+        return stop_daemons_and_execute(
+            target,
+            action,
+            &gradle_home,
+            options,
+            ram::locate_hsperfdata_dir,
+            ram::find_daemon_states,
+            stop_build_daemons,
+        );
+    }
+
+    execute_on_resource(target, action, &gradle_home, options)
+}
+
+// Running builds are looked for before stopping daemons, which would otherwise be killed in the middle of them
+fn stop_daemons_and_execute(
+    target: &MachineResource,
+    action: WipeAction,
+    gradle_home: &Path,
+    options: &ExecutionOptions,
+    hsperfdata_locator: fn() -> PathBuf,
+    daemon_states_reader: fn(&Path) -> HashMap<u32, DaemonState>,
+    daemons_stopper: fn(&Path, &ExecutionOptions) -> anyhow::Result<ExecutionOutcome>,
+) -> anyhow::Result<ExecutionOutcome> {
+    ensure_no_running_builds(
+        target,
+        action,
+        gradle_home,
+        options,
+        hsperfdata_locator,
+        daemon_states_reader,
+    )?;
+    let ram_outcome = daemons_stopper(gradle_home, options)?;
+    let disk_outcome = execute_action(target, action, gradle_home, options)?;
+    let sections = vec![(RamMemory, ram_outcome), (DiskSpace, disk_outcome)];
+    Ok(ExecutionOutcome::Combined(CombinedOutcome::new(sections)))
+}
+
+fn stop_build_daemons(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+    let daemons = vec![
+        MemoryCached::GradleBuildDaemon,
+        MemoryCached::GradleWorkerDaemon,
+        MemoryCached::KotlinCompilerDaemon,
+    ];
+    wipe_ram(gradle_home, daemons, options)
+}
+
+fn execute_on_resource(
+    target: &MachineResource,
+    action: WipeAction,
+    gradle_home: &Path,
+    options: &ExecutionOptions,
+) -> anyhow::Result<ExecutionOutcome> {
+    ensure_no_running_builds(
+        target,
+        action,
+        gradle_home,
+        options,
+        ram::locate_hsperfdata_dir,
+        ram::find_daemon_states,
+    )?;
+    execute_action(target, action, gradle_home, options)
+}
+
+fn ensure_no_running_builds(
+    target: &MachineResource,
+    action: WipeAction,
+    gradle_home: &Path,
+    options: &ExecutionOptions,
+    hsperfdata_locator: fn() -> PathBuf,
+    daemon_states_reader: fn(&Path) -> HashMap<u32, DaemonState>,
+) -> anyhow::Result<()> {
+    let touches_caches = !matches!(action, Evaluate | RestoreQuarantine | PurgeQuarantines);
+    if !matches!(target, DiskSpace) || !touches_caches || options.dry_run || options.force {
+        return Ok(());
+    }
+
+    let timeout = options.wait_for_builds.unwrap_or_default();
+    wait_for_running_builds(gradle_home, timeout, hsperfdata_locator, daemon_states_reader)
+}
+
+fn execute_action(
+    target: &MachineResource,
+    action: WipeAction,
+    gradle_home: &Path,
+    options: &ExecutionOptions,
+) -> anyhow::Result<ExecutionOutcome> {
     match (target, action) {
-        (RamMemory, Evaluate) => evaluate_ram_memory(),
-        (RamMemory, ShallowWipe) => shallow_wipe_ram(),
-        (RamMemory, DeepWipe) => deep_wipe_ram(),
-        (DiskSpace, Evaluate) => evaluate_disk_space(),
-        (DiskSpace, ShallowWipe) => shallow_wipe_disk(),
-        (DiskSpace, DeepWipe) => deep_wipe_ram_disk(),
+        (RamMemory, Evaluate) => evaluate_ram_memory(gradle_home, options),
+        (RamMemory, ShallowWipe) => shallow_wipe_ram(gradle_home, options),
+        (RamMemory, DeepWipe) => deep_wipe_ram(gradle_home, options),
+        (DiskSpace, Evaluate) => evaluate_disk(gradle_home, options),
+        (DiskSpace, ShallowWipe) => shallow_wipe_disk(gradle_home, options),
+        (DiskSpace, DeepWipe) => deep_wipe_ram_disk(gradle_home, options),
+        (DiskSpace, BudgetWipe(max_size)) => budget_wipe_disk(gradle_home, max_size, options),
+        (DiskSpace, SelectiveWipe) => selective_wipe_disk(gradle_home, options),
+        (DiskSpace, PruneDistributions) => prune_gradle_distributions(gradle_home, options),
+        (DiskSpace, PruneToolchains) => prune_jdk_toolchains(gradle_home, options),
+        (DiskSpace, RestoreQuarantine) => restore_quarantined_files(options),
+        (DiskSpace, PurgeQuarantines) => purge_quarantined_files(options),
+        (RamMemory, action) => bail!("The {action} is only supported for {DiskSpace}"),
+        (AllResources, Evaluate | ShallowWipe | DeepWipe) => execute_on_all_resources(action, gradle_home, options),
+        (AllResources, action) => bail!("The {action} is only supported for {DiskSpace}"),
     }
 }
 
-fn evaluate_ram_memory() -> anyhow::Result<ExecutionOutcome> {
+// RAM goes first, so that stopped daemons no longer hold the caches wiped afterwards
+fn execute_on_all_resources(
+    action: WipeAction,
+    gradle_home: &Path,
+    options: &ExecutionOptions,
+) -> anyhow::Result<ExecutionOutcome> {
+    let sections = [RamMemory, DiskSpace]
+        .into_iter()
+        .map(|resource| execute_on_resource(&resource, action, gradle_home, options).map(|outcome| (resource, outcome)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(ExecutionOutcome::Combined(CombinedOutcome::new(sections)))
+}
+
+// Removing caches underneath a running build breaks it in unpredictable ways
+fn wait_for_running_builds(
+    gradle_home: &Path,
+    timeout: Duration,
+    hsperfdata_locator: fn() -> PathBuf,
+    daemon_states_reader: fn(&Path) -> HashMap<u32, DaemonState>,
+) -> anyhow::Result<()> {
+    let deadline = Instant::now() + timeout;
+    let (mut running_builds, undetermined) = find_running_builds(gradle_home, hsperfdata_locator, daemon_states_reader);
+
+    undetermined
+        .iter()
+        .for_each(|process| warn!("Cannot tell whether {process} is running a build, assuming it is not"));
+
+    if !running_builds.is_empty() && !timeout.is_zero() {
+        info!("Waiting for running Gradle builds to finish ...");
+    }
+
+    while !running_builds.is_empty() && Instant::now() < deadline {
+        thread::sleep(RUNNING_BUILDS_POLLING_INTERVAL);
+        (running_builds, _) = find_running_builds(gradle_home, hsperfdata_locator, daemon_states_reader);
+    }
+
+    if running_builds.is_empty() {
+        return Ok(());
+    }
+
+    let evidences = running_builds
+        .iter()
+        .map(|evidence| format!("  - {evidence}"))
+        .join("\n");
+
+    bail!(
+        "Gradle builds seem to be running, wiping disk space now would break them :\n{evidences}\n\
+        Wait for them to finish (--wait), stop them (gradle-wiper ram shallow) or proceed anyway (--force)"
+    )
+}
+
+// Returns evidences of running builds, and the Gradle processes whose state could not be told
+fn find_running_builds(
+    gradle_home: &Path,
+    hsperfdata_locator: fn() -> PathBuf,
+    daemon_states_reader: fn(&Path) -> HashMap<u32, DaemonState>,
+) -> (Vec<String>, Vec<String>) {
+    let daemon_states = daemon_states_reader(gradle_home);
+    let (processes, undetermined) = ram::find_build_processes(hsperfdata_locator, &daemon_states);
+
+    let process_evidences = processes
+        .iter()
+        .map(|process| format!("{} ({}) : busy", process.launcher(), process.pid));
+
+    // Idle daemons release their locks as soon as another process asks for them
+    let lock_evidences = disk::find_lock_owners(gradle_home)
+        .into_iter()
+        .filter(|(_, owner)| {
+            let known = processes
+                .iter()
+                .chain(&undetermined)
+                .any(|process| process.pid == *owner);
+            let idle = matches!(daemon_states.get(owner), Some(state) if *state != DaemonState::Busy);
+            !known && !idle && *owner != std::process::id() && ram::is_process_running(*owner)
+        })
+        .map(|(lock, owner)| format!("{} : held by process {}", lock.to_string_lossy(), owner));
+
+    let undetermined = undetermined
+        .iter()
+        .map(|process| format!("{} ({})", process.launcher(), process.pid))
+        .collect::<Vec<_>>();
+
+    (
+        process_evidences.chain(lock_evidences).collect::<Vec<_>>(),
+        undetermined,
+    )
+}
+
+fn evaluate_ram_memory(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
     let resources = ram::find_resources_used_by_jvm(ram::locate_hsperfdata_dir, ram::convert_to_allocated_resources)?;
     let total_memory = calculate_total_allocated(&resources);
-    let outcome = EvaluationOutcome::new(resources, total_memory);
-    Ok(ExecutionOutcome::Evaluation(outcome))
+    let jvm_memory = ram::find_jvm_memory_usage(ram::locate_hsperfdata_dir)?;
+    let outcome = EvaluationOutcome::new(resources, total_memory).with_jvm_memory(jvm_memory);
+
+    if !options.per_process {
+        return Ok(ExecutionOutcome::Evaluation(outcome));
+    }
+
+    let daemon_states = ram::find_daemon_states(gradle_home);
+    let processes =
+        ram::find_processes_used_by_jvm(ram::locate_hsperfdata_dir, &daemon_states, ram::read_process_stats)?;
+    Ok(ExecutionOutcome::Evaluation(outcome.with_processes(processes)))
 }
 
-fn shallow_wipe_ram() -> anyhow::Result<ExecutionOutcome> {
-    let caches_to_remove = vec![MemoryCached::GradleBuildDaemon, MemoryCached::KotlinCompilerDaemon];
-    wipe_ram(caches_to_remove)
+fn shallow_wipe_ram(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+    let caches_to_remove = vec![
+        MemoryCached::GradleBuildDaemon,
+        MemoryCached::GradleWorkerDaemon,
+        MemoryCached::KotlinCompilerDaemon,
+        MemoryCached::MavenDaemon,
+        MemoryCached::BazelServer,
+    ];
+    wipe_ram(gradle_home, caches_to_remove, options)
 }
 
-fn deep_wipe_ram() -> anyhow::Result<ExecutionOutcome> {
+// IDEs are only reported, since stopping them would discard the work of their users
+fn deep_wipe_ram(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
     let caches_to_remove = vec![
         MemoryCached::GradleBuildDaemon,
+        MemoryCached::GradleWorkerDaemon,
         MemoryCached::KotlinCompilerDaemon,
+        MemoryCached::MavenDaemon,
+        MemoryCached::BazelServer,
+        MemoryCached::LanguageServer,
         MemoryCached::OtherJavaProcess,
     ];
-    wipe_ram(caches_to_remove)
+    wipe_ram(gradle_home, caches_to_remove, options)
 }
 
-fn wipe_ram(caches_to_remove: Vec<MemoryCached>) -> anyhow::Result<ExecutionOutcome> {
+fn wipe_ram(
+    gradle_home: &Path,
+    caches_to_remove: Vec<MemoryCached>,
+    options: &ExecutionOptions,
+) -> anyhow::Result<ExecutionOutcome> {
+    let caches_to_remove = filter_categories(caches_to_remove, &options.memory_categories)?;
+    ram::ensure_idleness_measurable(&caches_to_remove, &options.process_filters)?;
+    let daemon_states = ram::find_daemon_states(gradle_home);
+    let idle_daemons = ram::find_idle_daemons(gradle_home);
+    let candidates = ram::find_processes_to_cleanup(
+        ram::locate_hsperfdata_dir,
+        &caches_to_remove,
+        &options.process_filters,
+        &daemon_states,
+        &idle_daemons,
+    );
+    let (candidates, spared_workers) = spare_workers_of_protected_daemons(candidates, &daemon_states, options);
+
+    if options.dry_run {
+        let (processes, protected) =
+            ram::separate_protected_processes(candidates, &options.protection, options.force, ram::read_process_name);
+
+        protected
+            .iter()
+            .chain(&spared_workers)
+            .for_each(|process| warn!("Skipping {} ({}) : {}", process.launcher, process.pid, process.reason));
+
+        return simulate_ram_wipe(processes);
+    }
+
     let resources_before = find_resources_used_by_jvm(ram::locate_hsperfdata_dir, ram::convert_to_allocated_resources)?;
     let total_memory_before = calculate_total_allocated(&resources_before);
 
-    ram::cleanup_memory(ram::locate_hsperfdata_dir, &caches_to_remove);
+    let (stopped_processes, mut protected_processes, failures) =
+        ram::cleanup_memory(candidates, &options.protection, options.force, options.grace_period);
+    protected_processes.extend(spared_workers);
 
     let resources_after = find_resources_used_by_jvm(ram::locate_hsperfdata_dir, ram::convert_to_allocated_resources)?;
     let total_memory_after = calculate_total_allocated(&resources_after);
 
     let reclaimed = total_memory_before - total_memory_after;
+    let breakdown = calculate_reclaimed_per_use_case(&resources_before, &resources_after);
 
-    let outcome = WipingOutcome::new(reclaimed);
+    let outcome = WipingOutcome::new(reclaimed, breakdown, failures)
+        .with_stopped_processes(stopped_processes)
+        .with_protected_processes(protected_processes);
     Ok(ExecutionOutcome::Wiping(outcome))
 }
 
-fn evaluate_disk_space() -> anyhow::Result<ExecutionOutcome> {
+// Daemons are checked even when not wiped, since their workers could be wiped alone with --only
+fn spare_workers_of_protected_daemons(
+    candidates: Vec<JvmProcess>,
+    daemon_states: &HashMap<u32, DaemonState>,
+    options: &ExecutionOptions,
+) -> (Vec<JvmProcess>, Vec<ProtectedProcess>) {
+    let daemons = ram::find_processes_to_cleanup(
+        ram::locate_hsperfdata_dir,
+        &[MemoryCached::GradleBuildDaemon],
+        &ProcessFilters::default(),
+        daemon_states,
+        &HashMap::new(),
+    );
+
+    let (_, protected_daemons) =
+        ram::separate_protected_processes(daemons, &options.protection, options.force, ram::read_process_name);
+    ram::separate_workers_of_protected_daemons(candidates, &protected_daemons, ram::read_parent_pid)
+}
+
+fn simulate_ram_wipe(processes: Vec<JvmProcess>) -> anyhow::Result<ExecutionOutcome> {
+    let resources = processes
+        .into_iter()
+        .filter_map(|process| {
+            ram::convert_to_allocated_resources(&process).map(|(cached, memory)| {
+                let target = WipeTarget::Process {
+                    pid: process.pid,
+                    launcher: process.launcher().to_string(),
+                };
+                ReclaimableResource::new(target, UseCase::from(cached), ByteUnit::from(memory))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ExecutionOutcome::DryRun(summarize_dry_run(resources)))
+}
+
+fn evaluate_disk_space(gradle_home: &Path, discovery: &ProjectDiscovery) -> anyhow::Result<EvaluationOutcome> {
     let user_home = disk::user_home_locator();
-    let gradle_home = disk::find_gradle_home(user_home.as_path());
-    let gradle_home_resources = disk::resources_used_by_gradle_home(gradle_home.as_path())?;
+    let gradle_home_resources = disk::resources_used_by_gradle_home(gradle_home)?;
     let total_size_for_gradle_home = calculate_total_allocated(&gradle_home_resources);
 
     if gradle_home.exists() {
@@ -96,7 +392,7 @@ fn evaluate_disk_space() -> anyhow::Result<ExecutionOutcome> {
         debug!("Storage taken by Konan : {total_size_for_konan_caches}");
     }
 
-    let gradle_projects = disk::find_all_gradle_projects(user_home.to_path_buf());
+    let gradle_projects = disk::find_all_gradle_projects(user_home.to_path_buf(), discovery.clone());
     let gradle_projects_resources = disk::resources_used_by_gradle_projects(&gradle_projects)?;
     let total_size_for_gradle_projects = gradle_projects_resources.amount;
 
@@ -111,11 +407,24 @@ fn evaluate_disk_space() -> anyhow::Result<ExecutionOutcome> {
         + total_size_for_maven_local
         + total_size_for_gradle_projects;
 
-    let outcome = EvaluationOutcome::new(disk_resources, total_cached);
-    Ok(ExecutionOutcome::Evaluation(outcome))
+    Ok(EvaluationOutcome::new(disk_resources, total_cached))
+}
+
+fn evaluate_disk(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+    let discovery = &options.project_discovery;
+    let evaluation = evaluate_disk_space(gradle_home, discovery)?;
+
+    if !options.per_project {
+        return Ok(ExecutionOutcome::Evaluation(evaluation));
+    }
+
+    let user_home = disk::user_home_locator();
+    let gradle_projects = disk::find_all_gradle_projects(user_home, discovery.clone());
+    let projects = disk::resources_used_per_gradle_project_breakdown(&gradle_projects)?;
+    Ok(ExecutionOutcome::Evaluation(evaluation.with_projects(projects)))
 }
 
-fn shallow_wipe_disk() -> anyhow::Result<ExecutionOutcome> {
+fn shallow_wipe_disk(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
     let caches_to_remove = vec![
         DiskCached::Shared(UserLevelDiskCache::GradleBuildCaching),
         DiskCached::Shared(UserLevelDiskCache::GradleConfigurationCaching),
@@ -126,10 +435,10 @@ fn shallow_wipe_disk() -> anyhow::Result<ExecutionOutcome> {
         DiskCached::Standalone(ProjectLevelDiskCache::BuildOutput),
     ];
 
-    wipe_disk(caches_to_remove)
+    wipe_disk(gradle_home, caches_to_remove, options)
 }
 
-fn deep_wipe_ram_disk() -> anyhow::Result<ExecutionOutcome> {
+fn deep_wipe_ram_disk(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
     let caches_to_remove = vec![
         DiskCached::Shared(UserLevelDiskCache::GradleBuildCaching),
         DiskCached::Shared(UserLevelDiskCache::GradleConfigurationCaching),
@@ -146,30 +455,467 @@ fn deep_wipe_ram_disk() -> anyhow::Result<ExecutionOutcome> {
         DiskCached::Standalone(ProjectLevelDiskCache::IdeaMetadata),
     ];
 
-    wipe_disk(caches_to_remove)
+    wipe_disk(gradle_home, caches_to_remove, options)
+}
+
+fn selective_wipe_disk(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+    let Some(selection) = &options.selection else {
+        bail!("No caches were selected for wiping");
+    };
+
+    wipe_disk(gradle_home, selection.caches.clone(), options)
+}
+
+fn wipe_disk(
+    gradle_home: &Path,
+    caches_to_remove: Vec<DiskCached>,
+    options: &ExecutionOptions,
+) -> anyhow::Result<ExecutionOutcome> {
+    let caches_to_remove = filter_categories(caches_to_remove, &options.disk_categories)?;
+    let user_home = disk::user_home_locator();
+    let discovery = &options.project_discovery;
+    let targets = match options.older_than {
+        Some(max_age) => find_stale_targets(gradle_home, &caches_to_remove, max_age)?,
+        None => {
+            let gradle_projects = disk::find_all_gradle_projects(user_home.to_path_buf(), discovery.clone())
+                .into_iter()
+                .filter(|project| {
+                    let selected = options.selection.as_ref().map(|selection| &selection.projects);
+                    selected.is_none_or(|projects| projects.contains(project))
+                })
+                .collect::<Vec<_>>();
+
+            caches_to_remove
+                .into_iter()
+                .flat_map(|item| {
+                    disk::find_associated_filepaths(user_home.as_path(), gradle_home, &gradle_projects, item)
+                        .into_iter()
+                        .map(move |path| (item, path))
+                })
+                .collect::<Vec<(DiskCached, PathBuf)>>()
+        },
+    };
+
+    if options.dry_run {
+        return simulate_disk_wipe(targets);
+    }
+
+    let before_cleaning = evaluate_disk_space(gradle_home, discovery)?;
+    let (outcome, _) = remove_disk_targets(gradle_home, targets, options, before_cleaning)?;
+    Ok(ExecutionOutcome::Wiping(outcome))
+}
+
+fn filter_categories<T: PartialEq + Display>(categories: Vec<T>, filter: &CategoryFilter<T>) -> anyhow::Result<Vec<T>> {
+    if filter.is_empty() {
+        return Ok(categories);
+    }
+
+    let (allowed, skipped) = categories
+        .into_iter()
+        .partition::<Vec<_>, _>(|category| filter.allows(category));
+
+    skipped.iter().for_each(|category| debug!("Skipping {category}"));
+
+    if allowed.is_empty() {
+        bail!("None of the categories wiped in this mode are left after applying --only and --except");
+    }
+
+    Ok(allowed)
+}
+
+fn budget_wipe_disk(
+    gradle_home: &Path,
+    max_size: ByteUnit,
+    options: &ExecutionOptions,
+) -> anyhow::Result<ExecutionOutcome> {
+    let discovery = &options.project_discovery;
+    let before_cleaning = evaluate_disk_space(gradle_home, discovery)?;
+    let amount_to_free = before_cleaning.total_size - max_size;
+
+    debug!("Disk budget : {max_size}");
+    debug!("Storage to free : {amount_to_free}");
+
+    let evictable = find_evictable_entries(gradle_home, discovery)?
+        .into_iter()
+        .filter(|entry| options.disk_categories.allows(&entry.cached))
+        .collect::<Vec<_>>();
+
+    let targets = disk::select_least_recently_used(evictable, amount_to_free)
+        .into_iter()
+        .map(|entry| (entry.cached, entry.path))
+        .collect::<Vec<_>>();
+
+    if options.dry_run {
+        return simulate_disk_wipe(targets);
+    }
+
+    let (outcome, after_cleaning) = remove_disk_targets(gradle_home, targets, options, before_cleaning)?;
+
+    if after_cleaning.total_size > max_size {
+        warn!(
+            "Disk usage ({:.1}) still exceeds the budget of {:.1} after wiping every eligible entry",
+            after_cleaning.total_size, max_size
+        );
+    }
+
+    Ok(ExecutionOutcome::Wiping(outcome))
+}
+
+fn find_evictable_entries(gradle_home: &Path, discovery: &ProjectDiscovery) -> anyhow::Result<Vec<CacheEntry>> {
+    let user_home = disk::user_home_locator();
+    let maven_local_repository = disk::find_maven_local_repository(user_home.as_path());
+    let gradle_projects = disk::find_all_gradle_projects(user_home.to_path_buf(), discovery.clone());
+
+    let mut entries = disk::find_cache_entries(gradle_home, &maven_local_repository)?;
+
+    let coarse_caches = [
+        UserLevelDiskCache::GradleConfigurationCaching,
+        UserLevelDiskCache::GradleDaemonLogs,
+        UserLevelDiskCache::GradleTemporaryFiles,
+        UserLevelDiskCache::KonanCaches,
+    ];
+
+    for cache in coarse_caches {
+        let cached = DiskCached::Shared(cache);
+        for folder in disk::find_associated_filepaths(user_home.as_path(), gradle_home, &gradle_projects, cached) {
+            entries.extend(disk::entries_within_folder(&folder, cached)?);
+        }
+    }
+
+    let build_output = DiskCached::Standalone(ProjectLevelDiskCache::BuildOutput);
+    let build_output_folders =
+        disk::find_associated_filepaths(user_home.as_path(), gradle_home, &gradle_projects, build_output);
+    entries.extend(disk::entries_for_paths(&build_output_folders, build_output)?);
+
+    Ok(entries)
+}
+
+fn remove_disk_targets(
+    gradle_home: &Path,
+    targets: Vec<(DiskCached, PathBuf)>,
+    options: &ExecutionOptions,
+    before_cleaning: EvaluationOutcome,
+) -> anyhow::Result<(WipingOutcome, EvaluationOutcome)> {
+    let (removals, quarantine) = remove_paths(&targets, options)?;
+
+    let after_cleaning = evaluate_disk_space(gradle_home, &options.project_discovery)?;
+    let wiped = before_cleaning.total_size - after_cleaning.total_size;
+    let (reclaimed, quarantined) = split_quarantined(wiped, options);
+    let breakdown = calculate_reclaimed_per_use_case(&before_cleaning.resources, &after_cleaning.resources);
+    let outcome = WipingOutcome::new(reclaimed, breakdown, removal_failures(&removals))
+        .with_quarantine(quarantine, quarantined)
+        .with_removals(removals);
+
+    Ok((outcome, after_cleaning))
+}
+
+// Quarantined paths are moved away instead of removed, so that wipes can be undone
+fn remove_paths(
+    targets: &[(DiskCached, PathBuf)],
+    options: &ExecutionOptions,
+) -> anyhow::Result<(Vec<PathRemoval>, Option<String>)> {
+    if !options.quarantine {
+        let paths_to_remove = targets.iter().map(|(_, path)| path.clone()).collect::<Vec<_>>();
+        return Ok((disk::cleanup_resources(&paths_to_remove), None));
+    }
+
+    let (quarantine, removals) = disk::quarantine_resources(&disk::locate_quarantine_dir(), targets)?;
+    Ok((removals, quarantine))
+}
+
+// Quarantined files are moved away from caches, yet keep taking disk space until purged
+fn split_quarantined(wiped: ByteUnit, options: &ExecutionOptions) -> (ByteUnit, ByteUnit) {
+    match options.quarantine {
+        true => (ByteUnit::from(0), wiped),
+        false => (wiped, ByteUnit::from(0)),
+    }
+}
+
+fn removal_failures(removals: &[PathRemoval]) -> Vec<String> {
+    removals.iter().filter_map(PathRemoval::failure).collect()
 }
 
-fn wipe_disk(caches_to_remove: Vec<DiskCached>) -> anyhow::Result<ExecutionOutcome> {
-    let before_cleaning = evaluate_disk_space()?;
-    let before_cleaning_evaluation = before_cleaning.as_evaluation();
+fn restore_quarantined_files(options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+    let Some(id) = &options.quarantine_id else {
+        bail!("No quarantine to restore was given");
+    };
 
-    let paths_to_remove = caches_to_remove
+    if options.dry_run {
+        bail!("The --dry-run flag is not supported when restoring quarantined files");
+    }
+
+    let (restored, failures) = disk::restore_quarantine(&disk::locate_quarantine_dir(), id)?;
+    Ok(ExecutionOutcome::Restoring(RestoringOutcome::new(
+        id.clone(),
+        restored,
+        failures,
+    )))
+}
+
+fn purge_quarantined_files(options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+    let quarantine_dir = disk::locate_quarantine_dir();
+    let now = SystemTime::now();
+
+    let entries = disk::find_quarantined_entries(&quarantine_dir)
         .into_iter()
-        .flat_map(|item| disk::find_associated_filepaths(disk::user_home_locator().as_path(), item))
-        .collect::<Vec<PathBuf>>();
+        .filter(|entry| {
+            let age = now.duration_since(entry.last_used).unwrap_or_default();
+            options.older_than.is_none_or(|max_age| age >= max_age)
+        })
+        .collect::<Vec<_>>();
+
+    if options.dry_run {
+        let targets = entries.into_iter().map(|entry| (entry.cached, entry.path)).collect();
+        return simulate_disk_wipe(targets);
+    }
 
-    disk::cleanup_resources(&paths_to_remove);
+    let paths_to_remove = entries.iter().map(|entry| entry.path.clone()).collect::<Vec<_>>();
+    let removals = disk::cleanup_resources(&paths_to_remove);
+    disk::discard_purged_entries(&quarantine_dir)?;
 
-    let after_cleaning = evaluate_disk_space()?;
-    let after_cleaning_evaluation = after_cleaning.as_evaluation();
-    let reclaimed = before_cleaning_evaluation.total_size - after_cleaning_evaluation.total_size;
-    let outcome = WipingOutcome::new(reclaimed);
+    let purged = entries
+        .into_iter()
+        .filter(|entry| !entry.path.exists())
+        .collect::<Vec<_>>();
+
+    let reclaimed = purged
+        .iter()
+        .fold(ByteUnit::from(0), |total, entry| total + entry.amount);
+
+    let breakdown = purged
+        .into_iter()
+        .map(|entry| (UseCase::Disk(entry.cached), entry.amount))
+        .into_group_map()
+        .into_iter()
+        .map(|(use_case, amounts)| {
+            let amount = amounts
+                .into_iter()
+                .fold(ByteUnit::from(0), |total, amount| total + amount);
+            AllocatedResource::new(use_case, amount)
+        })
+        .sorted()
+        .collect::<Vec<_>>();
 
+    let outcome = WipingOutcome::new(reclaimed, breakdown, removal_failures(&removals)).with_removals(removals);
     Ok(ExecutionOutcome::Wiping(outcome))
 }
 
+fn find_stale_targets(
+    gradle_home: &Path,
+    caches_to_remove: &[DiskCached],
+    max_age: Duration,
+) -> anyhow::Result<Vec<(DiskCached, PathBuf)>> {
+    let user_home = disk::user_home_locator();
+    let maven_local_repository = disk::find_maven_local_repository(user_home.as_path());
+
+    let stale_entries = disk::find_stale_cache_entries(gradle_home, &maven_local_repository, max_age)?
+        .into_iter()
+        .filter(|entry| caches_to_remove.contains(&entry.cached))
+        .map(|entry| (entry.cached, entry.path))
+        .collect::<Vec<_>>();
+
+    debug!("Stale cache entries older than {:?} : {}", max_age, stale_entries.len());
+    Ok(stale_entries)
+}
+
+fn prune_gradle_distributions(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+    let user_home = disk::user_home_locator();
+    let gradle_projects = disk::find_all_gradle_projects(user_home, options.project_discovery.clone());
+
+    if gradle_projects.is_empty() {
+        bail!("No Gradle projects were found, cannot tell which Gradle distributions are still in use");
+    }
+
+    let referenced = disk::find_referenced_distributions(&gradle_projects);
+    debug!(
+        "Gradle distributions referenced by projects : {}",
+        referenced.iter().sorted().join(", ")
+    );
+
+    let (kept, removed) = disk::find_installed_distributions(gradle_home)
+        .into_iter()
+        .partition::<Vec<_>, _>(|path| {
+            path.file_name()
+                .is_some_and(|name| referenced.contains(name.to_string_lossy().as_ref()))
+        });
+
+    prune_disk(
+        DiskCached::Shared(UserLevelDiskCache::GradleDistributions),
+        kept,
+        removed,
+        options,
+    )
+}
+
+fn prune_jdk_toolchains(gradle_home: &Path, options: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+    let user_home = disk::user_home_locator();
+    let gradle_projects = disk::find_all_gradle_projects(user_home, options.project_discovery.clone());
+
+    if gradle_projects.is_empty() {
+        bail!("No Gradle projects were found, cannot tell which JDK toolchains are still in use");
+    }
+
+    let requested = disk::find_requested_toolchains(&gradle_projects)
+        .context("No JDK toolchains were pruned, since some of them could still be in use")?;
+    debug!("JDK toolchains requested by projects : {requested:?}");
+
+    let (kept, removed) = disk::find_installed_toolchains(gradle_home)
+        .into_iter()
+        .partition::<Vec<_>, _>(|installed| requested.iter().any(|request| request.matches(installed)));
+
+    let kept = kept.into_iter().map(|installed| installed.path).collect();
+    let removed = removed.into_iter().map(|installed| installed.path).collect();
+    prune_disk(
+        DiskCached::Shared(UserLevelDiskCache::GradleJDKToolchains),
+        kept,
+        removed,
+        options,
+    )
+}
+
+fn prune_disk(
+    cached: DiskCached,
+    kept: Vec<PathBuf>,
+    removed: Vec<PathBuf>,
+    options: &ExecutionOptions,
+) -> anyhow::Result<ExecutionOutcome> {
+    if options.dry_run {
+        let targets = removed.into_iter().map(|path| (cached, path)).collect();
+        return simulate_disk_wipe(targets);
+    }
+
+    let use_case = UseCase::Disk(cached);
+    let kept_resources = kept
+        .into_iter()
+        .map(|path| {
+            let amount = disk::disk_usage(&path)?;
+            Ok(PrunedResource::new(path, use_case, amount, true))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let removed_resources = removed
+        .into_iter()
+        .map(|path| {
+            let amount = disk::disk_usage(&path)?;
+            Ok(PrunedResource::new(path, use_case, amount, false))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let targets = removed_resources
+        .iter()
+        .map(|resource| (cached, resource.path.clone()))
+        .collect::<Vec<_>>();
+    let (removals, quarantine) = remove_paths(&targets, options)?;
+
+    let wiped = removed_resources
+        .iter()
+        .filter(|resource| !resource.path.exists())
+        .fold(ByteUnit::from(0), |total, resource| total + resource.amount);
+    let (reclaimed, quarantined) = split_quarantined(wiped, options);
+
+    let resources = kept_resources.into_iter().chain(removed_resources).collect();
+    let outcome = PruningOutcome::new(resources, reclaimed, removal_failures(&removals))
+        .with_quarantine(quarantine, quarantined)
+        .with_removals(removals);
+    Ok(ExecutionOutcome::Pruning(outcome))
+}
+
+fn simulate_disk_wipe(targets: Vec<(DiskCached, PathBuf)>) -> anyhow::Result<ExecutionOutcome> {
+    let resources = targets
+        .into_iter()
+        .filter(|(_, path)| path.exists())
+        .map(|(cached, path)| {
+            let amount = disk::disk_usage(path.as_path())?;
+            Ok(ReclaimableResource::new(
+                WipeTarget::Path(path),
+                UseCase::Disk(cached),
+                amount,
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(ExecutionOutcome::DryRun(summarize_dry_run(resources)))
+}
+
+fn summarize_dry_run(resources: Vec<ReclaimableResource>) -> DryRunOutcome {
+    let total_size = resources
+        .iter()
+        .fold(ByteUnit::from(0), |total, reclaimable| total + reclaimable.amount);
+
+    DryRunOutcome::new(resources, total_size)
+}
+
+fn calculate_reclaimed_per_use_case(
+    before: &[AllocatedResource],
+    after: &[AllocatedResource],
+) -> Vec<AllocatedResource> {
+    before
+        .iter()
+        .map(|previous| {
+            let remaining = after
+                .iter()
+                .find(|current| current.use_case == previous.use_case)
+                .map(|current| current.amount)
+                .unwrap_or(ByteUnit::from(0));
+
+            AllocatedResource::new(previous.use_case, previous.amount - remaining)
+        })
+        .filter(|reclaimed| reclaimed.amount > ByteUnit::from(0))
+        .collect::<Vec<_>>()
+}
+
 fn calculate_total_allocated(resources: &[AllocatedResource]) -> ByteUnit {
     resources
         .iter()
         .fold(ByteUnit::from(0), |total, allocation| total + allocation.amount)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::models::MachineResource::DiskSpace;
+    use crate::core::models::{DaemonState, ExecutionOptions, ExecutionOutcome, WipeAction};
+    use crate::core::wiper::stop_daemons_and_execute;
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+    use temp_dir::TempDir;
+
+    fn locate_fake_hsperfdata() -> PathBuf {
+        let root_dir = std::env::current_dir().expect("Cannot find current dir");
+        root_dir.join("test-data").join("hsperf")
+    }
+
+    fn fake_daemon_states(_: &Path) -> HashMap<u32, DaemonState> {
+        HashMap::from([(49739, DaemonState::Busy), (45927, DaemonState::Idle)])
+    }
+
+    fn fake_daemons_stopper(_: &Path, _: &ExecutionOptions) -> anyhow::Result<ExecutionOutcome> {
+        panic!("No daemons should be stopped while Gradle builds are running")
+    }
+
+    #[test]
+    fn should_not_stop_daemons_while_builds_are_running() {
+        let gradle_home = TempDir::new().expect("Cannot create temp dir");
+        let options = ExecutionOptions {
+            stop_daemons: true,
+            ..Default::default()
+        };
+
+        let outcome = stop_daemons_and_execute(
+            &DiskSpace,
+            WipeAction::ShallowWipe,
+            gradle_home.path(),
+            &options,
+            locate_fake_hsperfdata,
+            fake_daemon_states,
+            fake_daemons_stopper,
+        );
+
+        let Err(error) = outcome else {
+            panic!("Disk wipe should not proceed while Gradle builds are running");
+        };
+
+        let error = error.to_string();
+        assert!(error.contains("Gradle builds seem to be running"));
+        assert!(error.contains("(49739) : busy"));
+    }
+}
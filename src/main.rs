@@ -2,14 +2,29 @@
 // SPDX-License-Identifier: MIT
 
 use crate::core::wiper;
+use anyhow::bail;
 
 mod cli;
 mod core;
 
 fn main() -> anyhow::Result<()> {
-    let (target_resource, wipe_action, verbose_mode) = cli::parsed_arguments();
-    cli::setup_logging(verbose_mode);
+    let arguments = cli::parsed_arguments()?;
+    cli::setup_logging(arguments.verbose);
 
-    let outcome = wiper::execute(&target_resource, wipe_action)?;
-    cli::show_execution_outcome(&target_resource, &outcome)
+    let Some(options) = cli::select_wipe_targets(&arguments.action, arguments.options)? else {
+        return Ok(());
+    };
+
+    if !cli::confirm_wipe(&arguments.resource, &arguments.action, &options, arguments.assume_yes)? {
+        return Ok(());
+    }
+
+    let outcome = wiper::execute(&arguments.resource, arguments.action, &options)?;
+    cli::show_execution_outcome(&arguments.resource, &outcome, arguments.output)?;
+
+    if outcome.has_failures() {
+        bail!("Finished with failures, check the ones reported above");
+    }
+
+    Ok(())
 }